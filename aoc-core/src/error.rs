@@ -0,0 +1,86 @@
+use std::{error::Error, fmt};
+
+/// Where a puzzle input stopped making sense, and what we were expecting to find there.
+///
+/// Lines and columns are one indexed, to match what an editor shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// For parsers that only see a single line: the caller knows which line it was
+    pub fn in_line(column: usize, text: &str, expected: &str) -> ParseError {
+        ParseError::new(1, column, text, expected)
+    }
+
+    /// Shifts the error down by `offset` lines, for errors found in a slice of the input
+    pub fn offset_lines(mut self, offset: usize) -> ParseError {
+        self.line += offset;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found `{}`",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of the input with `parse_line`, tagging any error with the line it came from
+pub fn parse_lines<'a, T, F>(input: &'a str, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.offset_lines(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_number(line: &str) -> Result<u32, ParseError> {
+        line.parse::<u32>()
+            .map_err(|_| ParseError::in_line(1, line, "a number"))
+    }
+
+    #[test]
+    fn given_parse_error_display_includes_position_and_expectation() {
+        let error = ParseError::new(3, 5, "x", "a digit");
+        assert_eq!(error.to_string(), "line 3, column 5: expected a digit, found `x`");
+    }
+
+    #[test]
+    fn given_valid_lines_parse_lines_returns_values() {
+        let result = parse_lines("1\n2\n3", parse_number);
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn given_bad_third_line_parse_lines_reports_line_3() {
+        let result = parse_lines("1\n2\nthree", parse_number);
+        assert_eq!(result, Err(ParseError::new(3, 1, "three", "a number")));
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    env,
+    error::Error,
+    fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use crate::Solution;
+
+/// Names a directory holding every day's input as `day1.txt`, `day2.txt`, ...
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl InputSource {
+    /// `-` means standard input, anything else is a path to a file
+    pub fn from_arg(arg: &Path) -> InputSource {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_path_buf())
+        }
+    }
+
+    /// A day's input is `day{N}.txt` in the input directory if there is one,
+    /// otherwise the `input.txt` bundled with the day's crate
+    pub fn for_day(day: u8, input_dir: Option<&Path>, bundled: &Path) -> InputSource {
+        match input_dir {
+            Some(dir) => InputSource::File(dir.join(format!("day{}.txt", day))),
+            None => InputSource::File(bundled.to_path_buf()),
+        }
+    }
+
+    /// Reads the whole input and normalises it, see [`normalise`]
+    pub fn read(&self) -> Result<String, InputError> {
+        let text = match self {
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map_err(InputError::Stdin)?;
+                text
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => InputError::Missing(path.clone()),
+                _ => InputError::Unreadable(path.clone(), e),
+            })?,
+        };
+
+        Ok(normalise(&text))
+    }
+
+    /// Opens the input to be read a line at a time, for inputs too big to read all at once.
+    /// Unlike [`InputSource::read`] the text comes exactly as it is
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => {
+                let file = fs::File::open(path).map_err(|e| match e.kind() {
+                    io::ErrorKind::NotFound => InputError::Missing(path.clone()),
+                    _ => InputError::Unreadable(path.clone(), e),
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "no puzzle input at {} (save it there, or set {} to a directory of dayN.txt files)",
+                path.display(),
+                INPUT_DIR_VAR
+            ),
+            InputError::Unreadable(path, e) => write!(f, "could not read {}: {}", path.display(), e),
+            InputError::Stdin(e) => write!(f, "could not read stdin: {}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Missing(_) => None,
+            InputError::Unreadable(_, e) | InputError::Stdin(e) => Some(e),
+        }
+    }
+}
+
+/// The input directory given on the command line, or failing that in the environment
+pub fn input_dir(flag: Option<PathBuf>) -> Option<PathBuf> {
+    flag.or_else(|| env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+}
+
+/// Where a day's own binary reads from: the path (or `-`) given as its first argument,
+/// or else the day's input as found by [`InputSource::for_day`]
+pub fn day_input_source(day: u8, crate_dir: &str) -> InputSource {
+    let arg = env::args_os().nth(1).map(PathBuf::from);
+    day_input_source_from(day, crate_dir, arg.as_deref())
+}
+
+/// [`day_input_source`] for a binary that takes other arguments too,
+/// once it has picked out the input path (if any)
+pub fn day_input_source_from(day: u8, crate_dir: &str, arg: Option<&Path>) -> InputSource {
+    match arg {
+        Some(arg) => InputSource::from_arg(arg),
+        None => {
+            let bundled = Path::new(crate_dir).join("input.txt");
+            InputSource::for_day(day, input_dir(None).as_deref(), &bundled)
+        }
+    }
+}
+
+/// The whole of a day's own binary: reads the input from [`day_input_source`], then prints both
+/// answers, or else the error and a failing exit code
+pub fn run_day<S: Solution>(day: u8, crate_dir: &str) -> ExitCode {
+    let source = day_input_source(day, crate_dir);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{}, {}", S::part1(&parsed), S::part2(&parsed));
+    ExitCode::SUCCESS
+}
+
+/// Unix line endings, no byte order mark, and exactly one newline at the end,
+/// so parsers never see a stray `\r` or a phantom blank last line
+pub fn normalise(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut result = text.replace("\r\n", "\n").replace('\r', "\n");
+    result.truncate(result.trim_end_matches('\n').len());
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_crlf_and_blank_trailing_lines_normalise_returns_unix_text_with_one_newline() {
+        let result = normalise("1000\r\n2000\r\n\r\n3000\r\n\r\n\r\n");
+        assert_eq!(result, "1000\n2000\n\n3000\n");
+    }
+
+    #[test]
+    fn given_no_trailing_newline_normalise_adds_one() {
+        assert_eq!(normalise("A Y\nB X"), "A Y\nB X\n");
+    }
+
+    #[test]
+    fn given_leading_spaces_and_byte_order_mark_normalise_keeps_the_spaces() {
+        assert_eq!(normalise("\u{feff}    [D]\n"), "    [D]\n");
+    }
+
+    #[test]
+    fn given_only_newlines_normalise_returns_empty() {
+        assert_eq!(normalise("\n\r\n"), "");
+    }
+
+    #[test]
+    fn given_dash_from_arg_returns_stdin() {
+        assert_eq!(InputSource::from_arg(Path::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Path::new("in.txt")),
+            InputSource::File(PathBuf::from("in.txt"))
+        );
+    }
+
+    #[test]
+    fn given_input_dir_for_day_returns_day_file_in_it() {
+        let result = InputSource::for_day(7, Some(Path::new("inputs")), Path::new("day7/input.txt"));
+        assert_eq!(result, InputSource::File(PathBuf::from("inputs/day7.txt")));
+    }
+
+    #[test]
+    fn given_no_input_dir_for_day_returns_bundled_file() {
+        let result = InputSource::for_day(7, None, Path::new("day7/input.txt"));
+        assert_eq!(result, InputSource::File(PathBuf::from("day7/input.txt")));
+    }
+
+    #[test]
+    fn given_missing_file_read_returns_missing_error_naming_the_path() {
+        let path = env::temp_dir().join("aoc-core-no-such-input.txt");
+        let result = InputSource::File(path.clone()).read();
+        match result {
+            Err(InputError::Missing(missing)) => assert_eq!(missing, path),
+            other => panic!("expected a missing input error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn given_missing_file_reader_returns_missing_error() {
+        let path = env::temp_dir().join("aoc-core-no-such-input.txt");
+        assert!(matches!(InputSource::File(path).reader(), Err(InputError::Missing(_))));
+    }
+
+    #[test]
+    fn given_crlf_file_read_returns_normalised_text() {
+        let path = env::temp_dir().join(format!("aoc-core-input-{}.txt", std::process::id()));
+        fs::write(&path, "R 4\r\nU 4\r\n").unwrap();
+        let result = InputSource::File(path.clone()).read();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), "R 4\nU 4\n");
+    }
+}
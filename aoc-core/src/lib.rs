@@ -0,0 +1,10 @@
+mod error;
+mod input;
+mod solution;
+
+pub use error::{parse_lines, ParseError};
+pub use input::{
+    day_input_source, day_input_source_from, input_dir, normalise, run_day, InputError,
+    InputSource, INPUT_DIR_VAR,
+};
+pub use solution::{solve_part_a, solve_part_b, Solution};
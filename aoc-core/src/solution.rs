@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// A day's puzzle: one `parse` step shared by both parts, then the two parts themselves.
+///
+/// `Input` may borrow from the puzzle text, so days that only slice up the input
+/// don't have to copy it.
+pub trait Solution {
+    type Input<'a>;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer;
+}
+
+pub fn solve_part_a<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(S::part1(&parsed).to_string())
+}
+
+pub fn solve_part_b<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(S::part2(&parsed).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            Ok(input.split_whitespace().collect())
+        }
+
+        fn part1(input: &Vec<&str>) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Vec<&str>) -> usize {
+            input.iter().map(|w| w.len()).max().unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn given_words_solve_part_a_returns_word_count() {
+        assert_eq!(solve_part_a::<Words>("one two three"), Ok("3".to_string()));
+    }
+
+    #[test]
+    fn given_words_solve_part_b_returns_longest_word_length() {
+        assert_eq!(solve_part_b::<Words>("one two three"), Ok("5".to_string()));
+    }
+}
@@ -0,0 +1,3 @@
+mod matrix;
+
+pub use matrix::{Matrix, MatrixColumnIterator};
@@ -0,0 +1,382 @@
+use std::{
+    any::type_name,
+    fmt::Debug,
+    iter::{Rev, Skip},
+    slice::Iter,
+    str::FromStr,
+};
+
+use aoc_core::ParseError;
+
+/// A row-major grid of values, stored as a flat `Vec` plus its dimensions
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Matrix<T>
+where
+    T: Debug + Copy,
+{
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<T>,
+}
+
+impl<T: Debug + Copy> Matrix<T> {
+    /// Parses a grid where every character of every line is one value
+    pub fn from_string(input: &str) -> Result<Matrix<T>, ParseError>
+    where
+        T: FromStr,
+    {
+        let mut width = 0;
+        let mut height = 0;
+        let mut data = Vec::<T>::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let cells = line.chars().collect::<Vec<char>>();
+            if i == 0 {
+                width = cells.len();
+            }
+
+            if width != cells.len() {
+                let expected = format!("a row {} cells wide, the same as the first row", width);
+                return Err(ParseError::new(i + 1, width.min(cells.len()) + 1, line, &expected));
+            }
+
+            height = i + 1;
+
+            for (j, cell) in cells.iter().enumerate() {
+                let text = cell.to_string();
+                let value = text.parse::<T>().map_err(|_| {
+                    let expected = format!("a cell that parses as {}", type_name::<T>());
+                    ParseError::new(i + 1, j + 1, &text, &expected)
+                })?;
+                data.push(value);
+            }
+        }
+
+        Ok(Matrix {
+            width,
+            height,
+            data,
+        })
+    }
+
+    pub fn with_dimensions_and_initial_value(
+        width: usize,
+        height: usize,
+        initial_value: T,
+    ) -> Matrix<T> {
+        let data = vec![initial_value; width * height];
+
+        Matrix {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Takes an x, y (zero indexed!) and converts to an index in the vec
+    pub fn index_from_point(&self, x: usize, y: usize) -> usize {
+        self.width * y + x
+    }
+
+    /// Takes an index in the vec and converts it back to an x, y (zero indexed!)
+    pub fn point_from_index(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get_at_point(&self, row_index: usize, col_index: usize) -> &T {
+        let index = self.index_from_point(col_index, row_index);
+        &self.data[index]
+    }
+
+    pub fn set_at_point(&mut self, row_index: usize, col_index: usize, value: T) {
+        let index = self.index_from_point(col_index, row_index);
+        self.data[index] = value;
+    }
+
+    pub fn row_iter(&self, row_index: usize) -> Iter<'_, T> {
+        let row_start = row_index * self.width;
+        let row_end = row_start + self.width;
+        self.data[row_start..row_end].iter()
+    }
+
+    pub fn col_iter(&self, column_index: usize) -> MatrixColumnIterator<'_, T> {
+        MatrixColumnIterator::for_column_of_matrix(self, column_index)
+    }
+
+    pub fn left_iter(&self, row_index: usize, column_index: usize) -> Rev<Iter<'_, T>> {
+        let row_start = row_index * self.width;
+        let datum = self.index_from_point(column_index, row_index);
+        let slice = &self.data[row_start..datum];
+
+        slice.iter().rev()
+    }
+
+    pub fn right_iter(&self, row_index: usize, column_index: usize) -> Iter<'_, T> {
+        let datum = self.index_from_point(column_index, row_index);
+        let row_end = (row_index + 1) * self.width;
+        let slice = &self.data[(datum + 1)..row_end];
+
+        slice.iter()
+    }
+
+    pub fn up_iter(
+        &self,
+        row_index: usize,
+        column_index: usize,
+    ) -> Skip<Rev<MatrixColumnIterator<'_, T>>> {
+        let to_skip = self.height - row_index;
+        self.col_iter(column_index).rev().skip(to_skip)
+    }
+
+    pub fn down_iter(
+        &self,
+        row_index: usize,
+        column_index: usize,
+    ) -> Skip<MatrixColumnIterator<'_, T>> {
+        let to_skip = row_index + 1;
+        self.col_iter(column_index).skip(to_skip)
+    }
+
+    pub fn vec_size(&self) -> usize {
+        self.width * self.height
+    }
+}
+
+pub struct MatrixColumnIterator<'a, T>
+where
+    T: Debug + Copy,
+{
+    hm: &'a Matrix<T>,
+    column_index: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: Debug + Copy> MatrixColumnIterator<'a, T> {
+    fn for_column_of_matrix(hm: &'a Matrix<T>, column_index: usize) -> MatrixColumnIterator<'a, T> {
+        let back = hm.height;
+        MatrixColumnIterator::<T> {
+            hm,
+            column_index,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<'a, T: Debug + Copy> Iterator for MatrixColumnIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let vec_index = self.hm.index_from_point(self.column_index, self.front);
+        self.front += 1;
+        self.hm.data.get(vec_index)
+    }
+}
+
+impl<'a, T: Debug + Copy> DoubleEndedIterator for MatrixColumnIterator<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front >= self.back {
+            return None;
+        }
+        let vec_index = self.hm.index_from_point(self.column_index, self.back - 1);
+        self.back -= 1;
+        self.hm.data.get(vec_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static INPUT: &str = r"30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn given_test_input_matrix_from_string_has_correct_width() {
+        let result = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(result.width, 5);
+    }
+
+    #[test]
+    fn given_test_input_matrix_from_string_has_correct_height() {
+        let result = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(result.height, 5);
+    }
+
+    #[test]
+    fn given_test_input_matrix_from_string_has_correct_num_points() {
+        let result = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(result.data.len(), 25);
+    }
+
+    #[test]
+    fn given_test_input_matrix_from_string_has_correct_value_at_point() {
+        let result = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(result.data[12], 3);
+    }
+
+    #[test]
+    fn given_test_input_matrix_transforms_points_first_row() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let result = hm.index_from_point(2, 0);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn given_test_input_matrix_transforms_points_last_row() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let result = hm.index_from_point(4, 4);
+        assert_eq!(result, 24);
+    }
+
+    #[test]
+    fn given_test_input_matrix_transforms_index_back_to_point() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(hm.point_from_index(2), (2, 0));
+        assert_eq!(hm.point_from_index(13), (3, 2));
+        assert_eq!(hm.point_from_index(24), (4, 4));
+    }
+
+    #[test]
+    fn given_test_input_get_at_point_returns_correct_value() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        assert_eq!(hm.get_at_point(2, 0), &6);
+        assert_eq!(hm.get_at_point(0, 2), &3);
+    }
+
+    #[test]
+    fn given_ragged_row_matrix_from_string_returns_error() {
+        let result = Matrix::<u8>::from_string("303\n25\n653");
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                2,
+                3,
+                "25",
+                "a row 3 cells wide, the same as the first row"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_non_digit_cell_matrix_from_string_returns_error() {
+        let result = Matrix::<u8>::from_string("303\n2x5");
+        assert_eq!(
+            result,
+            Err(ParseError::new(2, 2, "x", "a cell that parses as u8"))
+        );
+    }
+
+    #[test]
+    fn given_dimensions_and_initial_value_matrix_is_filled() {
+        let result = Matrix::with_dimensions_and_initial_value(3, 2, false);
+        assert_eq!(result.width, 3);
+        assert_eq!(result.height, 2);
+        assert_eq!(result.data, vec![false; 6]);
+    }
+
+    #[test]
+    fn given_set_at_point_then_get_at_point_returns_new_value() {
+        let mut hm = Matrix::with_dimensions_and_initial_value(3, 2, 0usize);
+        hm.set_at_point(1, 2, 7);
+        assert_eq!(hm.get_at_point(1, 2), &7);
+        assert_eq!(hm.data, vec![0, 0, 0, 0, 0, 7]);
+    }
+
+    #[test]
+    fn given_test_input_matrix_row_iterator_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let result = hm.row_iter(1).copied().collect::<Vec<u8>>();
+        assert_eq!(result, vec![2, 5, 5, 1, 2]);
+    }
+
+    #[test]
+    fn given_test_input_matrix_column_iterator_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.col_iter(2);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn given_test_input_matrix_column_iterator_retruns_correct_back_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.col_iter(1);
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&0));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn given_test_input_matrix_column_iterator_retruns_correct_rev_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.col_iter(1).rev();
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn given_empty_matrix_column_iterator_returns_none() {
+        let hm = Matrix::<u8>::from_string("").unwrap();
+        let mut iter = hm.col_iter(0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn given_input_left_iterator_for_2_2_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.left_iter(2, 2);
+
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn given_input_right_iterator_for_2_2_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.right_iter(2, 2);
+
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn given_input_up_iterator_for_2_2_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.up_iter(2, 2);
+
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn given_input_down_iterator_for_2_2_returns_correct_sequence() {
+        let hm = Matrix::<u8>::from_string(INPUT).unwrap();
+        let mut iter = hm.down_iter(2, 2);
+
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+}
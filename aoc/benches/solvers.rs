@@ -0,0 +1,36 @@
+use std::hint::black_box;
+
+use aoc::days::{Part, DAYS};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Times both parts of every day, once on its `input.txt` and once on a generated input about a
+/// hundred times larger, so a solver that scales badly shows up even when the real input hides it
+fn bench_solvers(c: &mut Criterion) {
+    for day in DAYS {
+        // read the way the binaries read it, so trailing blank lines are gone before parsing
+        let real = day.input_source(None).read().unwrap();
+        // day 8's size is the side of a square grid, so ten times the side is a hundred times the trees
+        let scale = if day.number == 8 { 10 } else { 100 };
+        let synthetic = (day.generate)(day.puzzle_size * scale, 1);
+
+        let mut group = c.benchmark_group(format!("day{}", day.number));
+        group.sample_size(10);
+        for part in [Part::A, Part::B] {
+            let solver = day.solver(part);
+            group.bench_with_input(
+                BenchmarkId::new(part.to_string(), "input.txt"),
+                &real,
+                |b, input| b.iter(|| solver(black_box(input)).unwrap()),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(part.to_string(), "synthetic"),
+                &synthetic,
+                |b, input| b.iter(|| solver(black_box(input)).unwrap()),
+            );
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_solvers);
+criterion_main!(benches);
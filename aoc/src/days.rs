@@ -0,0 +1,203 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{solve_part_a, solve_part_b, InputSource, ParseError};
+use clap::ValueEnum;
+
+/// Every solver takes the raw puzzle input and returns the answer ready for printing,
+/// or where the input stopped making sense
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+/// Makes a puzzle input of roughly the given size, the same one every time for the same seed
+pub type Generator = fn(usize, u64) -> String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub part_a: Solver,
+    pub part_b: Solver,
+    /// The crate directory whose `input.txt` is used when no input is given
+    pub crate_dir: &'static str,
+    pub generate: Generator,
+    /// How big a real puzzle input is, in the units `generate` takes
+    pub puzzle_size: usize,
+}
+
+impl Day {
+    pub fn solver(&self, part: Part) -> Solver {
+        match part {
+            Part::A => self.part_a,
+            Part::B => self.part_b,
+        }
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.crate_dir)
+            .join("input.txt")
+    }
+
+    /// The day's input in `input_dir` if one is configured, otherwise its `input.txt`
+    pub fn input_source(&self, input_dir: Option<&Path>) -> InputSource {
+        InputSource::for_day(self.number, input_dir, &self.default_input())
+    }
+
+    /// The known answers for that same input: `dayN.answers.toml` beside it in `input_dir`,
+    /// otherwise the `answers.toml` beside the day's `input.txt`
+    pub fn answers_path(&self, input_dir: Option<&Path>) -> PathBuf {
+        match input_dir {
+            Some(dir) => dir.join(format!("day{}.answers.toml", self.number)),
+            None => self.default_input().with_file_name("answers.toml"),
+        }
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        part_a: solve_part_a::<day1::Day1>,
+        part_b: solve_part_b::<day1::Day1>,
+        crate_dir: "day1",
+        generate: day1::generate,
+        puzzle_size: 240,
+    },
+    Day {
+        number: 2,
+        part_a: solve_part_a::<day2::Day2>,
+        part_b: solve_part_b::<day2::Day2>,
+        crate_dir: "day2",
+        generate: day2::generate,
+        puzzle_size: 2500,
+    },
+    Day {
+        number: 3,
+        part_a: solve_part_a::<day3::Day3>,
+        part_b: solve_part_b::<day3::Day3>,
+        crate_dir: "day3",
+        generate: day3::generate,
+        puzzle_size: 300,
+    },
+    Day {
+        number: 4,
+        part_a: solve_part_a::<day4::Day4>,
+        part_b: solve_part_b::<day4::Day4>,
+        crate_dir: "day4",
+        generate: day4::generate,
+        puzzle_size: 1000,
+    },
+    Day {
+        number: 5,
+        part_a: solve_part_a::<day5::Day5>,
+        part_b: solve_part_b::<day5::Day5>,
+        crate_dir: "day5",
+        generate: day5::generate,
+        puzzle_size: 500,
+    },
+    Day {
+        number: 6,
+        part_a: solve_part_a::<day6::Day6>,
+        part_b: solve_part_b::<day6::Day6>,
+        crate_dir: "day6",
+        generate: day6::generate,
+        puzzle_size: 4096,
+    },
+    Day {
+        number: 7,
+        part_a: solve_part_a::<day7::Day7>,
+        part_b: solve_part_b::<day7::Day7>,
+        crate_dir: "day7",
+        generate: day7::generate,
+        puzzle_size: 185,
+    },
+    Day {
+        number: 8,
+        part_a: solve_part_a::<day8::Day8>,
+        part_b: solve_part_b::<day8::Day8>,
+        crate_dir: "day8",
+        generate: day8::generate,
+        puzzle_size: 99,
+    },
+    Day {
+        number: 9,
+        part_a: solve_part_a::<day9::Day9>,
+        part_b: solve_part_b::<day9::Day9>,
+        crate_dir: "day9",
+        generate: day9::generate,
+        puzzle_size: 2000,
+    },
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_days_registry_days_are_numbered_in_order() {
+        let numbers = DAYS.iter().map(|d| d.number).collect::<Vec<u8>>();
+        assert_eq!(numbers, (1..=9).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn given_day_7_find_day_returns_day_7() {
+        let day = find_day(7).unwrap();
+        assert_eq!(day.number, 7);
+    }
+
+    #[test]
+    fn given_day_25_find_day_returns_none() {
+        assert!(find_day(25).is_none());
+    }
+
+    #[test]
+    fn given_input_dir_answers_path_is_beside_the_input() {
+        let day = find_day(3).unwrap();
+        let result = day.answers_path(Some(Path::new("inputs")));
+        assert_eq!(result, PathBuf::from("inputs/day3.answers.toml"));
+    }
+
+    #[test]
+    fn given_puzzle_size_every_generated_input_solves() {
+        for day in DAYS {
+            let input = (day.generate)(day.puzzle_size, 1);
+            assert!((day.part_a)(&input).is_ok(), "day {}", day.number);
+            assert!((day.part_b)(&input).is_ok(), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn given_same_seed_every_day_generates_the_same_input() {
+        for day in DAYS {
+            let size = day.puzzle_size / 10;
+            assert_eq!((day.generate)(size, 7), (day.generate)(size, 7), "day {}", day.number);
+            assert_ne!((day.generate)(size, 7), (day.generate)(size, 8), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn given_every_day_default_input_exists() {
+        for day in DAYS {
+            assert!(day.default_input().exists(), "day {}", day.number);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::days::Day;
+
+/// Writes a generated input for the day to `dir/dayN.txt`, where `aoc run --input-dir` looks for
+/// it, refusing to replace an existing file unless `force` is set
+pub fn write_input(
+    day: &Day,
+    size: usize,
+    seed: u64,
+    dir: &Path,
+    force: bool,
+) -> Result<PathBuf, String> {
+    let path = dir.join(format!("day{}.txt", day.number));
+    let input = (day.generate)(size, seed);
+
+    fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    let file = if force {
+        fs::File::create(&path)
+    } else {
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+    };
+    let mut file = file.map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => format!(
+            "{} already exists (use --force to replace it)",
+            path.display()
+        ),
+        _ => format!("could not write {}: {}", path.display(), e),
+    })?;
+    file.write_all(input.as_bytes())
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::find_day;
+    use std::env;
+
+    #[test]
+    fn given_existing_file_write_input_only_replaces_it_when_forced() {
+        let dir = env::temp_dir().join(format!("aoc-generate-{}", std::process::id()));
+        let day = find_day(2).unwrap();
+
+        let path = write_input(day, 10, 1, &dir, false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), (day.generate)(10, 1));
+        assert!(write_input(day, 20, 1, &dir, false).is_err());
+        write_input(day, 20, 1, &dir, true).unwrap();
+        let rounds = fs::read_to_string(&path).unwrap().lines().count();
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(rounds, 20);
+    }
+}
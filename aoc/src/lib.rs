@@ -0,0 +1,3 @@
+pub mod days;
+pub mod generate;
+pub mod verify;
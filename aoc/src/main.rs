@@ -0,0 +1,156 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_core::{input_dir, InputSource};
+use clap::{Parser, Subcommand};
+
+use aoc::{
+    days::{find_day, Day, Part, DAYS},
+    generate::write_input,
+    verify::{mismatch_table, verify_day, Check},
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Read each day's input from DIR/dayN.txt [default: $AOC_INPUT_DIR, or each day's input.txt]
+    #[arg(long, global = true, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution, or every day if no day is given
+    Run {
+        /// The day to run (1-25)
+        day: Option<u8>,
+
+        /// Only run this part of the puzzle
+        #[arg(long, value_enum)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file, or from stdin if it is `-`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Check every day's answers against the ones recorded in its answers.toml
+    Verify {
+        /// Only verify this day (1-25)
+        day: Option<u8>,
+    },
+    /// Write generated inputs, as DIR/dayN.txt, for one day or every day
+    Generate {
+        /// Only generate this day's input (1-25)
+        day: Option<u8>,
+
+        /// How big to make each input [default: the size of a real puzzle input]
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// The same seed always generates the same input
+        #[arg(long, default_value_t = 2022)]
+        seed: u64,
+
+        /// Where to write the inputs [default: the input directory]
+        #[arg(long, value_name = "DIR")]
+        output: Option<PathBuf>,
+
+        /// Replace inputs that are already there
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input_dir = input_dir(cli.input_dir);
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input, input_dir),
+        Command::Verify { day } => verify(day, input_dir),
+        Command::Generate { day, size, seed, output, force } => {
+            generate(day, size, seed, output.or(input_dir), force)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+) -> Result<(), String> {
+    let days = select_days(day)?;
+    let parts = match part {
+        Some(p) => vec![p],
+        None => vec![Part::A, Part::B],
+    };
+
+    for day in days {
+        let source = match &input {
+            Some(path) => InputSource::from_arg(path),
+            None => day.input_source(input_dir.as_deref()),
+        };
+        let text = source.read().map_err(|e| format!("day {}: {}", day.number, e))?;
+
+        for part in parts.iter() {
+            let answer = day.solver(*part)(&text)
+                .map_err(|e| format!("day {} ({}): {}", day.number, source, e))?;
+            println!("Day {} part {}: {}", day.number, part, answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, input_dir: Option<PathBuf>) -> Result<(), String> {
+    let mut checks = Vec::new();
+    for day in select_days(day)? {
+        checks.append(&mut verify_day(day, input_dir.as_deref())?);
+    }
+
+    let failed = checks.iter().filter(|c| !c.passed()).collect::<Vec<&Check>>();
+    if failed.is_empty() {
+        println!("All {} answers match", checks.len());
+        return Ok(());
+    }
+
+    print!("{}", mismatch_table(&failed));
+    Err(format!("{} of {} answers do not match", failed.len(), checks.len()))
+}
+
+fn generate(
+    day: Option<u8>,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<PathBuf>,
+    force: bool,
+) -> Result<(), String> {
+    // the bundled input.txt files are real puzzle inputs, so never write over them
+    let dir = output.ok_or("nowhere to write the inputs: give --output, --input-dir or set AOC_INPUT_DIR")?;
+
+    for day in select_days(day)? {
+        let path = write_input(day, size.unwrap_or(day.puzzle_size), seed, &dir, force)?;
+        println!("Day {}: wrote {}", day.number, path.display());
+    }
+
+    Ok(())
+}
+
+/// The given day, or every day if none was given
+fn select_days(day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match day {
+        Some(number) => Ok(vec![find_day(number).ok_or(format!("no solution for day {}", number))?]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
@@ -0,0 +1,184 @@
+use std::{fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::days::{Day, Part};
+
+/// The known-correct answers for one day's input; a part left out hasn't been recorded yet
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// A missing answers file just means nothing has been recorded for that day yet
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn for_part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+}
+
+/// One part's answer, next to the one on record
+#[derive(Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!((&self.expected, &self.actual), (Some(expected), Ok(actual)) if expected == actual)
+    }
+}
+
+/// Runs both parts of the day on its input and compares them with its recorded answers
+pub fn verify_day(day: &Day, input_dir: Option<&Path>) -> Result<Vec<Check>, String> {
+    let answers = Answers::load(&day.answers_path(input_dir))?;
+    let input = day.input_source(input_dir).read().map_err(|e| e.to_string());
+
+    let checks = [Part::A, Part::B]
+        .iter()
+        .map(|&part| Check {
+            day: day.number,
+            part,
+            expected: answers.for_part(part).map(str::to_string),
+            actual: match &input {
+                Ok(text) => day.solver(part)(text).map_err(|e| e.to_string()),
+                Err(e) => Err(e.clone()),
+            },
+        })
+        .collect();
+
+    Ok(checks)
+}
+
+/// Lines up the checks in columns, one row each
+pub fn mismatch_table(checks: &[&Check]) -> String {
+    let mut rows = vec![[
+        "day".to_string(),
+        "part".to_string(),
+        "expected".to_string(),
+        "actual".to_string(),
+    ]];
+    for check in checks {
+        rows.push([
+            check.day.to_string(),
+            check.part.to_string(),
+            check.expected.clone().unwrap_or_else(|| "(not recorded)".to_string()),
+            match &check.actual {
+                Ok(answer) => answer.clone(),
+                Err(e) => format!("error: {}", e),
+            },
+        ]);
+    }
+
+    let mut widths = [0; 4];
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for row in rows.iter() {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn given_string_answers_parse_returns_both_parts() {
+        let result = Answers::parse("a = \"CMZ\"\nb = \"MCD\"\n").unwrap();
+        assert_eq!(result.for_part(Part::A), Some("CMZ"));
+        assert_eq!(result.for_part(Part::B), Some("MCD"));
+    }
+
+    #[test]
+    fn given_only_part_a_parse_leaves_part_b_unrecorded() {
+        let result = Answers::parse("a = \"24000\"").unwrap();
+        assert_eq!(result.for_part(Part::B), None);
+    }
+
+    #[test]
+    fn given_unknown_key_parse_returns_error() {
+        assert!(Answers::parse("c = \"1\"").is_err());
+    }
+
+    #[test]
+    fn given_missing_or_failed_answer_check_does_not_pass() {
+        let check = |expected: Option<&str>, actual: Result<&str, &str>| Check {
+            day: 1,
+            part: Part::A,
+            expected: expected.map(str::to_string),
+            actual: actual.map(str::to_string).map_err(str::to_string),
+        };
+        assert!(check(Some("1"), Ok("1")).passed());
+        assert!(!check(Some("1"), Ok("2")).passed());
+        assert!(!check(None, Ok("1")).passed());
+        assert!(!check(Some("1"), Err("bad input")).passed());
+    }
+
+    #[test]
+    fn given_mismatches_table_lines_up_columns() {
+        let wrong = Check {
+            day: 5,
+            part: Part::B,
+            expected: Some("PWPWHGFZS".to_string()),
+            actual: Ok("PWPWHGFZZ".to_string()),
+        };
+        let unrecorded = Check {
+            day: 12,
+            part: Part::A,
+            expected: None,
+            actual: Err("line 1, column 1: expected a digit, found `x`".to_string()),
+        };
+
+        let result = mismatch_table(&[&wrong, &unrecorded]);
+        let expected = "\
+day  part  expected        actual
+5    b     PWPWHGFZS       PWPWHGFZZ
+12   a     (not recorded)  error: line 1, column 1: expected a digit, found `x`
+";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_bundled_inputs_every_recorded_answer_matches() {
+        for day in DAYS {
+            let checks = verify_day(day, None).unwrap();
+            let failed = checks.iter().filter(|c| !c.passed()).collect::<Vec<&Check>>();
+            assert!(failed.is_empty(), "\n{}", mismatch_table(&failed));
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A calorie list for `size` elves, each carrying between 1 and 15 snacks
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let elves = (0..size)
+        .map(|_| {
+            let items = rng.random_range(1..=15);
+            (0..items)
+                .map(|_| rng.random_range(1000..=60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>();
+
+    elves.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_elves;
+
+    #[test]
+    fn given_size_100_generate_returns_100_elves() {
+        let result = parse_elves(&generate(100, 1)).unwrap();
+        assert_eq!(result.len(), 100);
+    }
+}
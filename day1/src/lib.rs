@@ -0,0 +1,533 @@
+use std::{cmp::Reverse, collections::BinaryHeap, mem, num::IntErrorKind};
+
+use aoc_core::{ParseError, Solution};
+
+mod generate;
+mod parallel;
+mod report;
+mod stream;
+
+pub use generate::generate;
+pub use parallel::summarise_parallel;
+pub use report::{report, Bucket, Carrier, Percentile, Report};
+pub use stream::{
+    elf_totals, elf_totals_with, summarise, summarise_with, ElfTotals, StreamError, Summary,
+};
+
+pub struct Day1;
+
+const TOP_ONE: usize = 1;
+const TOP_THREE: usize = 3;
+
+/// One elf's inventory: where it is in the list, and the calories of each item it carries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// In a `u128`, since a hand-built elf's items can add up to more than a `u64` holds
+    pub fn calories(&self) -> u128 {
+        self.items.iter().map(|item| u128::from(*item)).sum()
+    }
+}
+
+/// How forgiving to be about the calorie list. Blank lines, however many and whatever
+/// whitespace is on them, always just separate elves
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Skip lines starting with `#`
+    pub comments: bool,
+    /// Skip each line that isn't a calorie count, and say why, instead of stopping at the first
+    pub lenient: bool,
+}
+
+/// The elves, and in lenient mode the lines that were skipped over
+#[derive(Debug, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+    pub skipped: Vec<ParseError>,
+}
+
+/// One elf, by its position in the list, and the calories it carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub calories: u128,
+}
+
+impl From<&Elf> for ElfTotal {
+    fn from(elf: &Elf) -> ElfTotal {
+        ElfTotal {
+            index: elf.index,
+            calories: elf.calories(),
+        }
+    }
+}
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<Elf>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Vec<Elf>, ParseError> {
+        parse_elves(input)
+    }
+
+    fn part1(elves: &Vec<Elf>) -> u128 {
+        sum_top_n(elves, TOP_ONE)
+    }
+
+    fn part2(elves: &Vec<Elf>) -> u128 {
+        sum_top_n(elves, TOP_THREE)
+    }
+}
+
+/// Reads each elf's items, in the order the elves appear
+fn parse_elves(input: &str) -> Result<Vec<Elf>, ParseError> {
+    parse_elves_with(input, ParseOptions::default()).map(|inventory| inventory.elves)
+}
+
+pub fn parse_elves_with(input: &str, options: ParseOptions) -> Result<Inventory, ParseError> {
+    let mut grouping = Grouping::keeping_items(options);
+    let mut inventory = Inventory {
+        elves: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        match grouping.add_line(i + 1, line) {
+            Ok(Some(elf)) => inventory.elves.push(elf.into_elf()),
+            Ok(None) => {}
+            Err(e) if options.lenient => inventory.skipped.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+    inventory.elves.extend(grouping.finish().map(Finished::into_elf));
+
+    Ok(inventory)
+}
+
+/// Groups the input line by line: each number goes to the current elf, and the first
+/// blank line after it finishes it. A line that can't be added leaves the elf as it was.
+/// Only the running total is kept unless the items are asked for, so streaming an elf
+/// carrying any number of items takes the same memory
+#[derive(Debug, Default)]
+struct Grouping {
+    options: ParseOptions,
+    index: usize,
+    keep_items: bool,
+    items: Vec<u64>,
+    count: usize,
+    total: u64,
+    first_line: usize,
+}
+
+/// An elf the grouping has finished, with its items if it was keeping them
+#[derive(Debug, PartialEq, Eq)]
+struct Finished {
+    total: ElfTotal,
+    items: Vec<u64>,
+}
+
+impl Finished {
+    fn into_elf(self) -> Elf {
+        Elf {
+            index: self.total.index,
+            items: self.items,
+        }
+    }
+}
+
+/// A line that couldn't be added to an elf. An overflow names the elf, which part of the input
+/// can only number from its own start, so it becomes a [`ParseError`] once the elves before that
+/// part are counted
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LineError {
+    Invalid(ParseError),
+    Overflow {
+        elf: usize,
+        first_line: usize,
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl LineError {
+    /// The error, where `offset` elves came before the ones it was numbered among
+    fn into_parse_error(self, offset: usize) -> ParseError {
+        match self {
+            LineError::Invalid(e) => e,
+            LineError::Overflow {
+                elf,
+                first_line,
+                line,
+                column,
+                text,
+            } => {
+                let expected = format!(
+                    "a calorie count that keeps elf {} (from line {}) within {} in total",
+                    elf + offset,
+                    first_line,
+                    u64::MAX
+                );
+                ParseError::new(line, column, &text, &expected)
+            }
+        }
+    }
+}
+
+impl Grouping {
+    fn new(options: ParseOptions) -> Grouping {
+        Grouping {
+            options,
+            ..Grouping::default()
+        }
+    }
+
+    fn keeping_items(options: ParseOptions) -> Grouping {
+        Grouping {
+            keep_items: true,
+            ..Grouping::new(options)
+        }
+    }
+
+    /// The finished elf, if this was the blank line after it
+    fn add_line(&mut self, line_number: usize, line: &str) -> Result<Option<Finished>, ParseError> {
+        self.try_add_line(line_number, line).map_err(|e| e.into_parse_error(0))
+    }
+
+    /// [`Grouping::add_line`], leaving an overflowing elf's number to be offset later
+    fn try_add_line(
+        &mut self,
+        line_number: usize,
+        line: &str,
+    ) -> Result<Option<Finished>, LineError> {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if self.options.comments && line.starts_with('#') {
+            return Ok(None);
+        }
+
+        if line.is_empty() {
+            if self.count == 0 {
+                return Ok(None);
+            }
+            let elf = self.take();
+            self.index += 1;
+            return Ok(Some(elf));
+        }
+
+        let calories = line.parse::<u64>().map_err(|e| {
+            let expected = match e.kind() {
+                IntErrorKind::PosOverflow => format!("a calorie count no bigger than {}", u64::MAX),
+                _ if line.starts_with('-') => "a calorie count that isn't negative".to_string(),
+                _ => "a calorie count or a blank line".to_string(),
+            };
+            LineError::Invalid(ParseError::new(line_number, column, line, &expected))
+        })?;
+
+        if self.count == 0 {
+            self.first_line = line_number;
+        }
+        self.total = self.total.checked_add(calories).ok_or_else(|| LineError::Overflow {
+            elf: self.index,
+            first_line: self.first_line,
+            line: line_number,
+            column,
+            text: line.to_string(),
+        })?;
+        self.count += 1;
+        if self.keep_items {
+            self.items.push(calories);
+        }
+        Ok(None)
+    }
+
+    /// The last elf, at the end of the input, unless it ended with the one before
+    fn finish(mut self) -> Option<Finished> {
+        (self.count > 0).then(|| self.take())
+    }
+
+    /// The current elf, leaving the grouping ready for the next one
+    fn take(&mut self) -> Finished {
+        let elf = Finished {
+            total: ElfTotal {
+                index: self.index,
+                calories: u128::from(self.total),
+            },
+            items: mem::take(&mut self.items),
+        };
+        self.count = 0;
+        self.total = 0;
+        elf
+    }
+}
+
+/// Sums the calories carried by the `n` elves carrying the most
+pub fn sum_top_n(elves: &[Elf], n: usize) -> u128 {
+    top_n(elves, n).iter().map(|elf| elf.calories).sum()
+}
+
+/// The `n` elves carrying the most calories, most first, with ties going to the earlier elf
+pub fn top_n(elves: &[Elf], n: usize) -> Vec<ElfTotal> {
+    let mut most = TopN::new(n);
+    for elf in elves.iter() {
+        most.push(ElfTotal::from(elf));
+    }
+    most.into_sorted_vec()
+}
+
+/// The best `n` elves seen so far, however many there have been. A min-heap holds them,
+/// so the weakest of them is the one to drop when a better elf comes along
+#[derive(Debug)]
+pub struct TopN {
+    n: usize,
+    most: BinaryHeap<Reverse<(u128, Reverse<usize>)>>,
+}
+
+impl TopN {
+    pub fn new(n: usize) -> TopN {
+        TopN {
+            n,
+            most: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: ElfTotal) {
+        self.most.push(Reverse((elf.calories, Reverse(elf.index))));
+        if self.most.len() > self.n {
+            self.most.pop();
+        }
+    }
+
+    /// Most calories first, with ties going to the earlier elf
+    pub fn into_sorted_vec(self) -> Vec<ElfTotal> {
+        self.most
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| ElfTotal { index, calories })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = r###"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"###;
+
+    /// One elf per total, each carrying a single item
+    fn elves_carrying(totals: &[u64]) -> Vec<Elf> {
+        totals
+            .iter()
+            .enumerate()
+            .map(|(index, total)| Elf { index, items: vec![*total] })
+            .collect()
+    }
+
+    #[test]
+    fn given_test_input_parse_elves_returns_elf_totals() {
+        let result = parse_elves(TEST_INPUT).unwrap();
+        let totals = result.iter().map(Elf::calories).collect::<Vec<u128>>();
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn given_test_input_parse_elves_keeps_each_elfs_items() {
+        let result = parse_elves(TEST_INPUT).unwrap();
+        assert_eq!(result[0], Elf { index: 0, items: vec![1000, 2000, 3000] });
+        assert_eq!(result[4], Elf { index: 4, items: vec![10000] });
+    }
+
+    #[test]
+    fn given_non_numeric_line_parse_elves_returns_error_on_that_line() {
+        let result = parse_elves("1000\n2000\n\nlots");
+        assert_eq!(
+            result,
+            Err(ParseError::new(4, 1, "lots", "a calorie count or a blank line"))
+        );
+    }
+
+    #[test]
+    fn given_whitespace_only_and_repeated_blank_lines_parse_elves_separates_once() {
+        let result = parse_elves("\n\n1000\n2000\n   \n\t\n\n3000\n  \n").unwrap();
+        let totals = result.iter().map(Elf::calories).collect::<Vec<u128>>();
+        assert_eq!(totals, vec![3000, 3000]);
+        assert_eq!(result[1].index, 1);
+    }
+
+    #[test]
+    fn given_crlf_lines_parse_elves_returns_elf_totals() {
+        let result = Day1::parse("1000\r\n2000\r\n\r\n3000\r\n").unwrap();
+        assert_eq!(Day1::part2(&result), 6000);
+    }
+
+    #[test]
+    fn given_no_calorie_counts_parse_elves_returns_no_elves() {
+        assert_eq!(parse_elves("").unwrap(), vec![]);
+        assert_eq!(parse_elves("\n  \n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn given_comments_parse_elves_only_skips_them_when_allowed() {
+        let input = "# the first elf\n1000\n# still the first elf\n2000\n\n3000";
+        assert!(parse_elves(input).is_err());
+
+        let options = ParseOptions {
+            comments: true,
+            ..ParseOptions::default()
+        };
+        let result = parse_elves_with(input, options).unwrap();
+        let totals = result.elves.iter().map(Elf::calories).collect::<Vec<u128>>();
+        assert_eq!(totals, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn given_bad_lines_lenient_parse_elves_skips_each_and_says_why() {
+        let options = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+        let result = parse_elves_with("1000\n  lots\n2000\n\n-5\n3000", options).unwrap();
+
+        let totals = result.elves.iter().map(Elf::calories).collect::<Vec<u128>>();
+        assert_eq!(totals, vec![3000, 3000]);
+        assert_eq!(
+            result.skipped,
+            vec![
+                ParseError::new(2, 3, "lots", "a calorie count or a blank line"),
+                ParseError::new(5, 1, "-5", "a calorie count that isn't negative"),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_negative_count_parse_elves_returns_error_saying_so() {
+        let result = parse_elves("1000\n-200");
+        assert_eq!(
+            result,
+            Err(ParseError::new(2, 1, "-200", "a calorie count that isn't negative"))
+        );
+    }
+
+    #[test]
+    fn given_count_beyond_u64_parse_elves_returns_error_on_that_line() {
+        let result = parse_elves("18446744073709551616").unwrap_err();
+        assert_eq!(result.line, 1);
+        assert_eq!(result.expected, "a calorie count no bigger than 18446744073709551615");
+    }
+
+    #[test]
+    fn given_elf_total_beyond_u64_parse_elves_names_the_elf_that_overflowed() {
+        let result = parse_elves("1\n\n18446744073709551615\n0\n1").unwrap_err();
+        let expected =
+            "a calorie count that keeps elf 1 (from line 3) within 18446744073709551615 in total";
+        assert_eq!(result, ParseError::new(5, 1, "1", expected));
+    }
+
+    #[test]
+    fn given_many_items_streaming_grouping_keeps_only_the_running_total() {
+        let mut grouping = Grouping::new(ParseOptions::default());
+        for i in 0..1000 {
+            assert_eq!(grouping.add_line(i + 1, "7"), Ok(None));
+        }
+        assert!(grouping.items.is_empty());
+
+        let elf = grouping.add_line(1001, "").unwrap().unwrap();
+        let total = ElfTotal { index: 0, calories: 7000 };
+        assert_eq!(elf, Finished { total, items: Vec::new() });
+    }
+
+    #[test]
+    fn given_elves_at_u64_max_parts_return_exact_totals() {
+        let max = u64::MAX.to_string();
+        let input = format!("{}\n\n{}\n\n18446744073709551614\n1", max, max);
+        let elves = Day1::parse(&input).unwrap();
+        assert_eq!(Day1::part1(&elves), u128::from(u64::MAX));
+        assert_eq!(Day1::part2(&elves), 3 * u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn given_hand_built_elf_beyond_u64_top_n_and_report_return_its_total() {
+        let elves = vec![Elf { index: 0, items: vec![u64::MAX, u64::MAX, 2] }];
+        let total = 2 * u128::from(u64::MAX) + 2;
+        assert_eq!(elves[0].calories(), total);
+        assert_eq!(sum_top_n(&elves, 1), total);
+        assert_eq!(top_n(&elves, 1), vec![ElfTotal { index: 0, calories: total }]);
+        assert_eq!(report(&elves).calories, total);
+    }
+
+    #[test]
+    fn given_test_input_sum_top_n_returns_running_totals() {
+        let elves = Day1::parse(TEST_INPUT).unwrap();
+        assert_eq!(sum_top_n(&elves, 0), 0);
+        assert_eq!(sum_top_n(&elves, 2), 35000);
+        assert_eq!(sum_top_n(&elves, 5), 55000);
+    }
+
+    #[test]
+    fn given_more_than_elf_count_sum_top_n_returns_total_of_all_elves() {
+        let result = sum_top_n(&elves_carrying(&[1, 2]), 10);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn given_test_input_top_n_returns_indices_and_totals_most_first() {
+        let elves = Day1::parse(TEST_INPUT).unwrap();
+        let result = top_n(&elves, 3);
+        let expected = vec![
+            ElfTotal { index: 3, calories: 24000 },
+            ElfTotal { index: 2, calories: 11000 },
+            ElfTotal { index: 4, calories: 10000 },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_tied_totals_top_n_prefers_the_earlier_elf() {
+        let result = top_n(&elves_carrying(&[5, 7, 5, 7]), 3);
+        let indices = result.iter().map(|elf| elf.index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![1, 3, 0]);
+    }
+
+    #[test]
+    fn given_1000_of_many_elves_top_n_matches_a_full_sort() {
+        let elves = parse_elves(&generate(5000, 3)).unwrap();
+        let mut sorted = elves.iter().map(Elf::calories).collect::<Vec<u128>>();
+        sorted.sort_by(|a, b| b.cmp(a));
+
+        let result = top_n(&elves, 1000);
+        let totals = result.iter().map(|elf| elf.calories).collect::<Vec<u128>>();
+        assert_eq!(totals, sorted[..1000]);
+        assert!(result.iter().all(|elf| elves[elf.index].calories() == elf.calories));
+    }
+
+    #[test]
+    fn given_test_input_get_24000() {
+        let elves = Day1::parse(TEST_INPUT).unwrap();
+        let result = Day1::part1(&elves);
+        assert_eq!(result, 24000);
+    }
+
+    #[test]
+    fn given_test_input_get_45000() {
+        let elves = Day1::parse(TEST_INPUT).unwrap();
+        let result = Day1::part2(&elves);
+        assert_eq!(result, 45000);
+    }
+}
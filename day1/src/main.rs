@@ -0,0 +1,89 @@
+use std::{env, ffi::OsString, fs, path::Path, process::ExitCode, thread};
+
+use aoc_core::{day_input_source_from, InputSource, ParseError};
+use day1::{parse_elves_with, report, summarise_parallel, summarise_with, ParseOptions};
+
+static USAGE: &str = "usage: day1 [--report [--json]] [--comments] [--lenient] [INPUT]";
+static FLAGS: [&str; 4] = ["--report", "--json", "--comments", "--lenient"];
+
+/// Input files at least this big are summarised on every core
+const PARALLEL_BYTES: u64 = 16 * 1024 * 1024;
+
+fn main() -> ExitCode {
+    // `--report` prints statistics about the elves instead of the answers, and `--json` prints
+    // them as JSON. `--comments` skips `#` lines, and `--lenient` skips (and warns about) bad lines
+    let (flags, paths): (Vec<OsString>, Vec<OsString>) =
+        env::args_os().skip(1).partition(|arg| arg.to_string_lossy().starts_with("--"));
+    let has_flag = |flag: &str| flags.iter().any(|f| f == flag);
+    let unknown_flag = flags.iter().any(|f| !FLAGS.iter().any(|known| f == known));
+    if paths.len() > 1 || unknown_flag || (has_flag("--json") && !has_flag("--report")) {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let options = ParseOptions {
+        comments: has_flag("--comments"),
+        lenient: has_flag("--lenient"),
+    };
+    let input = paths.first().map(Path::new);
+    let source = day_input_source_from(1, env!("CARGO_MANIFEST_DIR"), input);
+    let result = if has_flag("--report") {
+        print_report(&source, options, has_flag("--json"))
+    } else {
+        print_answers(&source, options)
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Splits a big input file between threads, and otherwise reads the elves a line at a time, so
+/// however many there are only the top three are kept in memory
+fn print_answers(source: &InputSource, options: ParseOptions) -> Result<(), String> {
+    let summary = if is_big_file(source) {
+        let input = source.read().map_err(|e| e.to_string())?;
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        summarise_parallel(&input, 3, threads, options).map_err(|e| format!("{}: {}", source, e))?
+    } else {
+        let reader = source.reader().map_err(|e| e.to_string())?;
+        summarise_with(reader, 3, options).map_err(|e| format!("{}: {}", source, e))?
+    };
+    warn_skipped(source, &summary.skipped);
+
+    let most = summary.top.first().map_or(0, |elf| elf.calories);
+    let top_three = summary.top.iter().map(|elf| elf.calories).sum::<u128>();
+    println!("Result: {}, {}", most, top_three);
+    Ok(())
+}
+
+fn is_big_file(source: &InputSource) -> bool {
+    match source {
+        InputSource::File(path) => fs::metadata(path).is_ok_and(|m| m.len() >= PARALLEL_BYTES),
+        InputSource::Stdin => false,
+    }
+}
+
+fn print_report(source: &InputSource, options: ParseOptions, json: bool) -> Result<(), String> {
+    let input = source.read().map_err(|e| e.to_string())?;
+    let inventory = parse_elves_with(&input, options).map_err(|e| format!("{}: {}", source, e))?;
+    warn_skipped(source, &inventory.skipped);
+
+    let report = report(&inventory.elves);
+    if json {
+        println!("{}", report.to_json());
+    } else {
+        print!("{}", report);
+    }
+    Ok(())
+}
+
+fn warn_skipped(source: &InputSource, skipped: &[ParseError]) {
+    for e in skipped {
+        eprintln!("warning: {}: skipped {}", source, e);
+    }
+}
@@ -0,0 +1,234 @@
+use std::thread;
+
+use aoc_core::ParseError;
+
+use crate::{ElfTotal, Grouping, LineError, ParseOptions, Summary, TopN};
+
+/// [`summarise_with`](crate::summarise_with) for an input that is already in memory, split at
+/// blank lines into one chunk per thread. Each thread finds its own chunk's top `n`, and the
+/// chunks are merged in order, so the result is exactly what the sequential pass gives
+pub fn summarise_parallel(
+    input: &str,
+    n: usize,
+    threads: usize,
+    options: ParseOptions,
+) -> Result<Summary, ParseError> {
+    let chunks = split_at_blank_lines(input, threads.max(1));
+
+    // every chunk starts on a fresh line, so its first line number is one more than the
+    // number of lines before it
+    let mut first_lines = Vec::with_capacity(chunks.len());
+    let mut line = 1;
+    for chunk in chunks.iter() {
+        first_lines.push(line);
+        line += chunk.matches('\n').count();
+    }
+
+    let results = thread::scope(|scope| {
+        let handles = chunks
+            .iter()
+            .zip(first_lines.iter())
+            .map(|(chunk, first_line)| {
+                scope.spawn(move || summarise_chunk(chunk, *first_line, n, options))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<Result<ChunkSummary, LineError>>>()
+    });
+
+    // the chunks are in input order, so an elf's index is its index in its chunk plus every elf
+    // before the chunk, and the first error found is the first in the input
+    let mut elves = 0;
+    let mut calories = 0;
+    let mut most = TopN::new(n);
+    let mut skipped = Vec::new();
+    for result in results {
+        let chunk = result.map_err(|e| e.into_parse_error(elves))?;
+        for mut elf in chunk.top {
+            elf.index += elves;
+            most.push(elf);
+        }
+        skipped.extend(chunk.skipped.into_iter().map(|e| e.into_parse_error(elves)));
+        elves += chunk.elves;
+        calories += chunk.calories;
+    }
+
+    Ok(Summary {
+        elves,
+        calories,
+        top: most.into_sorted_vec(),
+        skipped,
+    })
+}
+
+/// One chunk's summary, with its elves numbered from 0
+struct ChunkSummary {
+    elves: usize,
+    calories: u128,
+    top: Vec<ElfTotal>,
+    skipped: Vec<LineError>,
+}
+
+fn summarise_chunk(
+    chunk: &str,
+    first_line: usize,
+    n: usize,
+    options: ParseOptions,
+) -> Result<ChunkSummary, LineError> {
+    let mut grouping = Grouping::new(options);
+    let mut elves = 0;
+    let mut calories = 0;
+    let mut most = TopN::new(n);
+    let mut skipped = Vec::new();
+
+    let mut add = |elf: ElfTotal| {
+        elves += 1;
+        calories += elf.calories;
+        most.push(elf);
+    };
+    for (i, line) in chunk.lines().enumerate() {
+        match grouping.try_add_line(first_line + i, line) {
+            Ok(Some(elf)) => add(elf.total),
+            Ok(None) => {}
+            Err(e) if options.lenient => skipped.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+    if let Some(elf) = grouping.finish() {
+        add(elf.total);
+    }
+
+    Ok(ChunkSummary {
+        elves,
+        calories,
+        top: most.into_sorted_vec(),
+        skipped,
+    })
+}
+
+/// About `chunks` pieces of roughly equal size, each starting at a blank line (or the start of
+/// the input) so that no elf is split between two of them
+fn split_at_blank_lines(input: &str, chunks: usize) -> Vec<&str> {
+    let target = input.len() / chunks + 1;
+    let mut result = Vec::with_capacity(chunks);
+    let mut rest = input;
+
+    while rest.len() > target {
+        match find_blank_line(rest, target) {
+            Some(at) => {
+                result.push(&rest[..at]);
+                rest = &rest[at..];
+            }
+            None => break,
+        }
+    }
+    result.push(rest);
+
+    result
+}
+
+/// Where the first blank (or whitespace-only) line starting after byte `from` begins
+fn find_blank_line(text: &str, from: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut start = from + bytes[from..].iter().position(|b| *b == b'\n')? + 1;
+
+    while start < bytes.len() {
+        let end = bytes[start..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(bytes.len(), |e| start + e);
+        if text[start..end].trim().is_empty() {
+            return Some(start);
+        }
+        start = end + 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, summarise_with, Day1, StreamError};
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_generated_input_summarise_parallel_matches_the_sequential_pass() {
+        let input = generate(2000, 6);
+        let sequential = summarise_with(input.as_bytes(), 3, ParseOptions::default()).unwrap();
+
+        for threads in [1, 2, 3, 8, 64] {
+            let result = summarise_parallel(&input, 3, threads, ParseOptions::default()).unwrap();
+            assert_eq!(result, sequential, "{} threads", threads);
+        }
+
+        let elves = Day1::parse(&input).unwrap();
+        let result = summarise_parallel(&input, 3, 8, ParseOptions::default()).unwrap();
+        let top_three = result.top.iter().map(|elf| elf.calories).sum::<u128>();
+        assert_eq!(top_three, Day1::part2(&elves));
+    }
+
+    #[test]
+    fn given_tied_elves_in_different_chunks_summarise_parallel_prefers_the_earlier_elf() {
+        let input = "5\n\n7\n\n5\n\n7\n";
+        let result = summarise_parallel(input, 3, 4, ParseOptions::default()).unwrap();
+        let indices = result.top.iter().map(|elf| elf.index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![1, 3, 0]);
+    }
+
+    #[test]
+    fn given_bad_lines_in_several_chunks_summarise_parallel_reports_the_first() {
+        let input = generate(200, 7) + "\nlots\n\n" + &generate(200, 8) + "\nmore\n";
+        let bad_line = input.lines().position(|line| line == "lots").unwrap() + 1;
+
+        let result = summarise_parallel(&input, 3, 4, ParseOptions::default()).unwrap_err();
+        assert_eq!(result.line, bad_line);
+    }
+
+    #[test]
+    fn given_overflow_in_a_later_chunk_summarise_parallel_names_the_elf_as_sequential_pass_does() {
+        let input = generate(200, 7) + "\n18446744073709551615\n1\n\n" + &generate(200, 8);
+        let lenient = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+
+        let sequential = match summarise_with(input.as_bytes(), 3, ParseOptions::default()) {
+            Err(StreamError::Parse(e)) => e,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+        let result = summarise_parallel(&input, 3, 4, ParseOptions::default()).unwrap_err();
+        assert_eq!(result, sequential);
+
+        let sequential = summarise_with(input.as_bytes(), 3, lenient).unwrap();
+        let result = summarise_parallel(&input, 3, 4, lenient).unwrap();
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result, sequential);
+    }
+
+    #[test]
+    fn given_lenient_mode_summarise_parallel_skips_lines_in_input_order() {
+        let input = generate(200, 7) + "\nlots\n\n" + &generate(200, 8) + "\nmore\n";
+        let options = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+        let sequential = summarise_with(input.as_bytes(), 3, options).unwrap();
+
+        let result = summarise_parallel(&input, 3, 4, options).unwrap();
+        assert_eq!(result.skipped.len(), 2);
+        assert_eq!(result, sequential);
+    }
+
+    #[test]
+    fn given_input_split_at_blank_lines_every_chunk_after_the_first_starts_with_one() {
+        let input = generate(100, 9);
+        let chunks = split_at_blank_lines(&input, 4);
+
+        assert_eq!(chunks.concat(), input);
+        assert!(chunks.len() > 1);
+        assert!(chunks[1..].iter().all(|chunk| chunk.starts_with('\n')));
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::Elf;
+
+static PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+const BUCKETS: u128 = 10;
+const TOP_CARRIERS: usize = 3;
+const BAR_WIDTH: usize = 40;
+
+/// What the elves are carrying, taken all together
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Report {
+    pub elves: usize,
+    pub items: usize,
+    pub calories: u128,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub most_items: Vec<Carrier>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub calories: u128,
+}
+
+/// How many elves carry between `from` and `to` calories, inclusive
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u128,
+    pub to: u128,
+    pub elves: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Carrier {
+    pub index: usize,
+    pub items: usize,
+    pub calories: u128,
+}
+
+pub fn report(elves: &[Elf]) -> Report {
+    let mut totals = elves.iter().map(Elf::calories).collect::<Vec<u128>>();
+    totals.sort_unstable();
+    let calories = totals.iter().sum::<u128>();
+
+    // the most items first, with ties going to the earlier elf
+    let mut carriers = elves
+        .iter()
+        .map(|elf| Carrier {
+            index: elf.index,
+            items: elf.items.len(),
+            calories: elf.calories(),
+        })
+        .collect::<Vec<Carrier>>();
+    carriers.sort_by(|a, b| b.items.cmp(&a.items).then(a.index.cmp(&b.index)));
+    carriers.truncate(TOP_CARRIERS);
+
+    Report {
+        elves: elves.len(),
+        items: elves.iter().map(|elf| elf.items.len()).sum(),
+        calories,
+        mean: if totals.is_empty() {
+            0.0
+        } else {
+            calories as f64 / totals.len() as f64
+        },
+        median: median(&totals),
+        percentiles: PERCENTILES
+            .iter()
+            .map(|&percent| Percentile {
+                percent,
+                calories: percentile(&totals, percent),
+            })
+            .collect(),
+        histogram: histogram(&totals),
+        most_items: carriers,
+    }
+}
+
+fn median(sorted: &[u128]) -> f64 {
+    let n = sorted.len();
+    match n {
+        0 => 0.0,
+        _ if n % 2 == 1 => sorted[n / 2] as f64,
+        _ => (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0,
+    }
+}
+
+/// The nearest rank: the smallest total that at least `percent`% of the elves don't exceed
+fn percentile(sorted: &[u128], percent: u8) -> u128 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (usize::from(percent) * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Splits the range from the smallest total to the largest into (at most) `BUCKETS` equal parts
+fn histogram(sorted: &[u128]) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    // no elf can hold anywhere near u128::MAX in u64 items, so the range and one more fits
+    let width = (max - min + 1).div_ceil(BUCKETS);
+
+    let mut buckets = Vec::new();
+    let mut from = min;
+    while from <= max {
+        let to = (from + width - 1).min(max);
+        let elves = sorted.iter().filter(|c| (from..=to).contains(*c)).count();
+        buckets.push(Bucket { from, to, elves });
+        from = to + 1;
+    }
+
+    buckets
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "elves     {}", self.elves)?;
+        writeln!(f, "items     {}", self.items)?;
+        writeln!(f, "calories  {}", self.calories)?;
+        writeln!(f, "mean      {:.1}", self.mean)?;
+        writeln!(f, "median    {:.1}", self.median)?;
+
+        writeln!(f, "\npercentile  calories")?;
+        for p in self.percentiles.iter() {
+            writeln!(f, "{:<10}  {}", format!("p{}", p.percent), p.calories)?;
+        }
+
+        let ranges = self
+            .histogram
+            .iter()
+            .map(|b| format!("{}-{}", b.from, b.to))
+            .collect::<Vec<String>>();
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0).max(8);
+        let most_elves = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0).max(1);
+        writeln!(f, "\n{:<range_width$}  elves", "calories", range_width = range_width)?;
+        for (range, bucket) in ranges.iter().zip(self.histogram.iter()) {
+            let bar = "#".repeat((bucket.elves * BAR_WIDTH).div_ceil(most_elves));
+            let row = format!(
+                "{:<range_width$}  {:<5}  {}",
+                range,
+                bucket.elves,
+                bar,
+                range_width = range_width
+            );
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        writeln!(f, "\n{:<6}  {:<5}  calories", "elf", "items")?;
+        for c in self.most_items.iter() {
+            writeln!(f, "{:<6}  {:<5}  {}", c.index, c.items, c.calories)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_elves;
+
+    static TEST_INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn given_test_input_report_returns_counts_and_averages() {
+        let result = report(&parse_elves(TEST_INPUT).unwrap());
+        assert_eq!(result.elves, 5);
+        assert_eq!(result.items, 10);
+        assert_eq!(result.calories, 55000);
+        assert_eq!(result.mean, 11000.0);
+        assert_eq!(result.median, 10000.0);
+    }
+
+    #[test]
+    fn given_test_input_report_returns_nearest_rank_percentiles() {
+        let result = report(&parse_elves(TEST_INPUT).unwrap());
+        let calories = result.percentiles.iter().map(|p| p.calories).collect::<Vec<u128>>();
+        assert_eq!(calories, vec![4000, 6000, 10000, 11000, 24000, 24000]);
+    }
+
+    #[test]
+    fn given_test_input_report_buckets_every_elf_once() {
+        let result = report(&parse_elves(TEST_INPUT).unwrap());
+        assert_eq!(result.histogram.first().unwrap().from, 4000);
+        assert_eq!(result.histogram.last().unwrap().to, 24000);
+        assert_eq!(result.histogram.iter().map(|b| b.elves).sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn given_test_input_report_lists_elves_with_most_items_first() {
+        let result = report(&parse_elves(TEST_INPUT).unwrap());
+        let indices = result.most_items.iter().map(|c| c.index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![0, 3, 2]);
+    }
+
+    #[test]
+    fn given_even_number_of_elves_median_averages_the_middle_two() {
+        assert_eq!(median(&[1, 2, 4, 8]), 3.0);
+    }
+
+    #[test]
+    fn given_one_elf_report_has_one_bucket() {
+        let result = report(&parse_elves("500\n").unwrap());
+        assert_eq!(result.histogram, vec![Bucket { from: 500, to: 500, elves: 1 }]);
+    }
+
+    #[test]
+    fn given_totals_from_zero_to_u64_max_report_buckets_the_whole_range() {
+        let input = "0\n\n18446744073709551615\n\n18446744073709551615";
+        let result = report(&parse_elves(input).unwrap());
+        assert_eq!(result.calories, 2 * u128::from(u64::MAX));
+        assert_eq!(result.histogram.len(), 10);
+        assert_eq!(result.histogram.last().unwrap().to, u128::from(u64::MAX));
+        let counts = result.histogram.iter().map(|b| b.elves).collect::<Vec<usize>>();
+        assert_eq!(counts, [1, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn given_report_to_json_round_trips_the_numbers() {
+        let result = report(&parse_elves(TEST_INPUT).unwrap()).to_json();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(json["calories"], 55000);
+        assert_eq!(json["most_items"][0]["items"], 3);
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use aoc_core::ParseError;
+
+use crate::{ElfTotal, Grouping, ParseOptions, TopN};
+
+/// Each elf's total, read a line at a time from `reader`, so only the current line and the
+/// current elf are ever held in memory
+pub fn elf_totals<R: BufRead>(reader: R) -> ElfTotals<R> {
+    elf_totals_with(reader, ParseOptions::default())
+}
+
+/// [`elf_totals`], where in lenient mode each bad line is an error but the elves carry on after it
+pub fn elf_totals_with<R: BufRead>(reader: R, options: ParseOptions) -> ElfTotals<R> {
+    ElfTotals {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        grouping: Some(Grouping::new(options)),
+    }
+}
+
+pub struct ElfTotals<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    /// None once the input is used up or has gone wrong
+    grouping: Option<Grouping>,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<ElfTotal, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let grouping = self.grouping.as_mut()?;
+
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    let last = self.grouping.take().and_then(Grouping::finish);
+                    return last.map(|elf| Ok(elf.total));
+                }
+                Ok(_) => {
+                    self.line_number += 1;
+                    let line = self.line.strip_suffix('\n').unwrap_or(&self.line);
+                    let line = line.strip_suffix('\r').unwrap_or(line);
+                    // a byte order mark, as `aoc_core::normalise` drops from whole inputs
+                    let line = match self.line_number {
+                        1 => line.strip_prefix('\u{feff}').unwrap_or(line),
+                        _ => line,
+                    };
+                    match grouping.add_line(self.line_number, line) {
+                        Ok(Some(elf)) => return Some(Ok(elf.total)),
+                        Ok(None) => {}
+                        Err(e) => {
+                            if !grouping.options.lenient {
+                                self.grouping = None;
+                            }
+                            return Some(Err(StreamError::Parse(e)));
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.grouping = None;
+                    return Some(Err(StreamError::Read(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Everything about the elves that can be worked out in one pass over the input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub elves: usize,
+    pub calories: u128,
+    pub top: Vec<ElfTotal>,
+    /// The lines skipped over in lenient mode
+    pub skipped: Vec<ParseError>,
+}
+
+/// Counts and totals the elves as they are read, keeping only the `n` carrying the most
+pub fn summarise<R: BufRead>(reader: R, n: usize) -> Result<Summary, StreamError> {
+    summarise_with(reader, n, ParseOptions::default())
+}
+
+pub fn summarise_with<R: BufRead>(
+    reader: R,
+    n: usize,
+    options: ParseOptions,
+) -> Result<Summary, StreamError> {
+    let mut elves = 0;
+    let mut calories = 0;
+    let mut most = TopN::new(n);
+    let mut skipped = Vec::new();
+
+    for elf in elf_totals_with(reader, options) {
+        match elf {
+            Ok(elf) => {
+                elves += 1;
+                calories += elf.calories;
+                most.push(elf);
+            }
+            Err(StreamError::Parse(e)) if options.lenient => skipped.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(Summary {
+        elves,
+        calories,
+        top: most.into_sorted_vec(),
+        skipped,
+    })
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Read(e) => write!(f, "could not read input: {}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Read(e) => Some(e),
+            StreamError::Parse(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, parse_elves, top_n, Elf};
+
+    fn totals(input: &str, options: ParseOptions) -> Result<Vec<u128>, StreamError> {
+        elf_totals_with(input.as_bytes(), options)
+            .map(|elf| elf.map(|elf| elf.calories))
+            .collect()
+    }
+
+    #[test]
+    fn given_generated_input_elf_totals_matches_parse_elves() {
+        let input = generate(500, 4);
+        let result = elf_totals(input.as_bytes())
+            .collect::<Result<Vec<ElfTotal>, StreamError>>()
+            .unwrap();
+        let expected = parse_elves(&input)
+            .unwrap()
+            .iter()
+            .map(ElfTotal::from)
+            .collect::<Vec<ElfTotal>>();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_crlf_lines_elf_totals_returns_elf_totals() {
+        let result = totals("1000\r\n2000\r\n\r\n3000\r\n", ParseOptions::default()).unwrap();
+        assert_eq!(result, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn given_byte_order_mark_elf_totals_skips_it() {
+        let result = totals("\u{feff}1000\n2000\n\n3000\n", ParseOptions::default()).unwrap();
+        assert_eq!(result, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn given_blank_runs_and_trailing_blanks_elf_totals_returns_one_total_per_elf() {
+        let result = totals("\n1000\n \t\n\n\r\n2000\n\n\n", ParseOptions::default()).unwrap();
+        assert_eq!(result, vec![1000, 2000]);
+    }
+
+    #[test]
+    fn given_bad_line_elf_totals_yields_earlier_elves_then_stops_at_the_error() {
+        let mut totals = elf_totals("1000\n\n2000\nlots\n\n3000\n".as_bytes());
+        assert!(matches!(totals.next(), Some(Ok(ElfTotal { calories: 1000, .. }))));
+        match totals.next() {
+            Some(Err(StreamError::Parse(e))) => assert_eq!(e.line, 4),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(totals.next().is_none());
+    }
+
+    #[test]
+    fn given_bad_lines_lenient_summarise_skips_them_and_carries_on() {
+        let options = ParseOptions {
+            comments: true,
+            lenient: true,
+        };
+        let input = "# inventory\n1000\nlots\n\n2000\n-5\n# end\n";
+        let result = summarise_with(input.as_bytes(), 3, options).unwrap();
+
+        assert_eq!(result.elves, 2);
+        assert_eq!(result.calories, 3000);
+        let lines = result.skipped.iter().map(|e| e.line).collect::<Vec<usize>>();
+        assert_eq!(lines, vec![3, 6]);
+    }
+
+    #[test]
+    fn given_generated_input_summarise_matches_the_whole_input_answers() {
+        let input = generate(500, 5);
+        let elves = parse_elves(&input).unwrap();
+
+        let result = summarise(input.as_bytes(), 3).unwrap();
+        assert_eq!(result.elves, elves.len());
+        assert_eq!(result.calories, elves.iter().map(Elf::calories).sum::<u128>());
+        assert_eq!(result.top, top_n(&elves, 3));
+        assert!(result.skipped.is_empty());
+    }
+}
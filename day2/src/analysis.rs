@@ -0,0 +1,207 @@
+use std::{cmp::Reverse, fmt};
+
+use aoc_core::{parse_lines, ParseError};
+
+use crate::{
+    interpretation::{parse_letters, OUTCOMES, SHAPES},
+    Response, Round, Shape,
+};
+
+static KEYS: [char; 3] = ['X', 'Y', 'Z'];
+
+static OPPONENT: [(char, Shape); 3] = [
+    ('A', Shape::Rock),
+    ('B', Shape::Paper),
+    ('C', Shape::Scissors),
+];
+
+static PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+/// What each of `X`, `Y` and `Z` means, in that order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping(pub [Response; 3]);
+
+/// The guide scored under one mapping, with the rounds it makes out of each line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored {
+    pub mapping: Mapping,
+    pub total: u32,
+    pub rounds: Vec<Round>,
+}
+
+/// The guide scored under every way of reading the second column: each ordering of
+/// `X`, `Y` and `Z` as rock, paper and scissors, and as lose, draw and win
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// The opponent's shape and the key on each line of the guide
+    pub lines: Vec<(Shape, char)>,
+    /// Highest total first; mappings with the same total stay in the order they were tried
+    pub scored: Vec<Scored>,
+}
+
+impl Mapping {
+    /// All twelve mappings, the six as shapes first
+    pub fn all() -> Vec<Mapping> {
+        let as_shapes = PERMUTATIONS
+            .iter()
+            .map(|p| p.map(|i| Response::Shape(SHAPES[i])));
+        let as_outcomes = PERMUTATIONS
+            .iter()
+            .map(|p| p.map(|i| Response::Outcome(OUTCOMES[i])));
+        as_shapes.chain(as_outcomes).map(Mapping).collect()
+    }
+
+    pub fn response(&self, key: char) -> Option<Response> {
+        KEYS.iter().position(|k| *k == key).map(|i| self.0[i])
+    }
+}
+
+/// Scores a guide written with `A`, `B`, `C` and `X`, `Y`, `Z` under every mapping
+pub fn analyse(input: &str) -> Result<Analysis, ParseError> {
+    let keys = KEYS.map(|k| (k, k));
+    let lines = parse_lines(input, |line| parse_letters(line, &OPPONENT, &keys))?;
+
+    let mut scored = Mapping::all()
+        .into_iter()
+        .map(|mapping| {
+            let rounds = lines
+                .iter()
+                .map(|(opponent, key)| Round::new(*opponent, mapping.response(*key).unwrap()))
+                .collect::<Vec<Round>>();
+            let total = rounds.iter().map(Round::score).sum();
+            Scored {
+                mapping,
+                total,
+                rounds,
+            }
+        })
+        .collect::<Vec<Scored>>();
+    scored.sort_by_key(|s| Reverse(s.total));
+
+    Ok(Analysis { lines, scored })
+}
+
+impl Analysis {
+    pub fn best(&self) -> &Scored {
+        &self.scored[0]
+    }
+
+    pub fn worst(&self) -> &Scored {
+        &self.scored[self.scored.len() - 1]
+    }
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = KEYS
+            .iter()
+            .zip(self.0.iter())
+            .map(|(key, response)| format!("{} {}", key, response))
+            .collect::<Vec<String>>();
+        f.pad(&pairs.join(", "))
+    }
+}
+
+/// Every mapping's total, then each line's round under the best and the worst of them
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<32}  total", "mapping")?;
+        for s in self.scored.iter() {
+            writeln!(f, "{:<32}  {}", s.mapping, s.total)?;
+        }
+
+        let (best, worst) = (self.best(), self.worst());
+        writeln!(f, "\nbest   {}  {}", best.mapping, best.total)?;
+        writeln!(f, "worst  {}  {}", worst.mapping, worst.total)?;
+
+        writeln!(
+            f,
+            "\n{:<6}  {:<8}  {:<3}  {:<8}  {:<4}  {:<5}  {:<8}  {:<4}  score",
+            "line", "opponent", "key", "best", "ends", "score", "worst", "ends"
+        )?;
+        for (i, (opponent, key)) in self.lines.iter().enumerate() {
+            let (b, w) = (&best.rounds[i], &worst.rounds[i]);
+            writeln!(
+                f,
+                "{:<6}  {:<8}  {:<3}  {:<8}  {:<4}  {:<5}  {:<8}  {:<4}  {}",
+                i + 1,
+                opponent,
+                key,
+                b.me(),
+                b.outcome(),
+                b.score(),
+                w.me(),
+                w.outcome(),
+                w.score()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, Day2, Outcome};
+    use aoc_core::Solution;
+
+    static TEST_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn given_test_input_analyse_finds_best_and_worst_mapping() {
+        let result = analyse(TEST_INPUT).unwrap();
+        assert_eq!(result.scored.len(), 12);
+
+        // X scissors, Y paper, Z rock wins every round; rock, scissors, paper loses every one
+        let best = result.best();
+        let scissors_paper_rock = [Shape::Scissors, Shape::Paper, Shape::Rock];
+        assert_eq!(
+            best.mapping,
+            Mapping(scissors_paper_rock.map(Response::Shape))
+        );
+        assert_eq!(best.total, 24);
+        assert_eq!(
+            best.rounds.iter().map(Round::score).collect::<Vec<u32>>(),
+            vec![8, 9, 7]
+        );
+
+        let worst = result.worst();
+        assert_eq!(worst.total, 6);
+        assert!(worst.rounds.iter().all(|r| r.outcome() == Outcome::Lose));
+    }
+
+    #[test]
+    fn given_generated_guide_analyse_includes_both_parts_answers() {
+        let input = generate(300, 8);
+        let guide = Day2::parse(&input).unwrap();
+        let result = analyse(&input).unwrap();
+
+        let total = |mapping: Mapping| {
+            result
+                .scored
+                .iter()
+                .find(|s| s.mapping == mapping)
+                .unwrap()
+                .total
+        };
+        let part1 = Mapping(SHAPES.map(Response::Shape));
+        let part2 = Mapping(OUTCOMES.map(Response::Outcome));
+        assert_eq!(total(part1), Day2::part1(&guide));
+        assert_eq!(total(part2), Day2::part2(&guide));
+        assert!(result.scored.windows(2).all(|w| w[0].total >= w[1].total));
+    }
+
+    #[test]
+    fn given_bad_line_analyse_returns_error_on_that_line() {
+        let result = analyse("A Y\nB Q").unwrap_err();
+        assert_eq!(result.line, 2);
+    }
+}
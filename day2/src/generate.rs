@@ -0,0 +1,29 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A strategy guide of `size` rounds
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = String::with_capacity(size * 4);
+    for _ in 0..size {
+        let opponent = ['A', 'B', 'C'][rng.random_range(0..3)];
+        let key = ['X', 'Y', 'Z'][rng.random_range(0..3)];
+        result.push(opponent);
+        result.push(' ');
+        result.push(key);
+        result.push('\n');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Interpretation;
+
+    #[test]
+    fn given_size_100_generate_returns_100_rounds() {
+        let result = Interpretation::shapes().parse(&generate(100, 1)).unwrap();
+        assert_eq!(result.len(), 100);
+    }
+}
@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use aoc_core::{parse_lines, ParseError};
+
+use crate::{Outcome, Response, Round, Shape};
+
+pub(crate) static SHAPES: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+pub(crate) static OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+/// How to read a strategy guide: which letters in the first column are the opponent's shapes,
+/// and what each letter in the second column means
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    opponent: Vec<(char, Shape)>,
+    response: Vec<(char, Response)>,
+}
+
+impl Interpretation {
+    /// Every letter has to be a single visible character, different from the others in its column
+    pub fn new(
+        opponent: Vec<(char, Shape)>,
+        response: Vec<(char, Response)>,
+    ) -> Result<Interpretation, String> {
+        let opponent_letters = opponent.iter().map(|(c, _)| *c).collect::<Vec<char>>();
+        let response_letters = response.iter().map(|(c, _)| *c).collect::<Vec<char>>();
+        for (column, letters) in [("first", opponent_letters), ("second", response_letters)] {
+            if letters.is_empty() {
+                return Err(format!("no letters for the {} column", column));
+            }
+            if let Some(c) = letters.iter().find(|c| c.is_whitespace()) {
+                return Err(format!("{:?} can't be a letter in the {} column", c, column));
+            }
+            let repeated = (1..letters.len()).find(|&i| letters[..i].contains(&letters[i]));
+            if let Some(i) = repeated {
+                return Err(format!("`{}` means two things in the {} column", letters[i], column));
+            }
+        }
+
+        Ok(Interpretation { opponent, response })
+    }
+
+    /// Part 1: `X`, `Y` and `Z` are rock, paper and scissors
+    pub fn shapes() -> Interpretation {
+        Interpretation::with_letters(['A', 'B', 'C'], ['X', 'Y', 'Z'], false).unwrap()
+    }
+
+    /// Part 2: `X`, `Y` and `Z` mean lose, draw and win
+    pub fn outcomes() -> Interpretation {
+        Interpretation::with_letters(['A', 'B', 'C'], ['X', 'Y', 'Z'], true).unwrap()
+    }
+
+    /// `opponent` are the letters for rock, paper and scissors, and so are `response`, unless
+    /// they are `outcomes`, when they are the letters for lose, draw and win
+    pub fn with_letters(
+        opponent: [char; 3],
+        response: [char; 3],
+        outcomes: bool,
+    ) -> Result<Interpretation, String> {
+        let responses = if outcomes {
+            OUTCOMES.map(Response::Outcome)
+        } else {
+            SHAPES.map(Response::Shape)
+        };
+        Interpretation::new(
+            opponent.into_iter().zip(SHAPES).collect(),
+            response.into_iter().zip(responses).collect(),
+        )
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Vec<Round>, ParseError> {
+        parse_lines(input, |line| self.parse_round(line))
+    }
+
+    fn parse_round(&self, line: &str) -> Result<Round, ParseError> {
+        let (opponent, response) = parse_letters(line, &self.opponent, &self.response)?;
+        Ok(Round::new(opponent, response))
+    }
+}
+
+/// A line like `A Y`: a letter from `opponent`, a space, and a letter from `response`,
+/// looked up to find what they mean
+pub(crate) fn parse_letters<A: Copy, B: Copy>(
+    line: &str,
+    opponent: &[(char, A)],
+    response: &[(char, B)],
+) -> Result<(A, B), ParseError> {
+    let chars = line.char_indices().collect::<Vec<(usize, char)>>();
+    let letter = |n: usize| chars.get(n).map(|(_, c)| *c);
+    let rest = |n: usize| chars.get(n).map_or("", |(i, _)| &line[*i..]);
+
+    let first = letter(0).and_then(|c| lookup(opponent, c)).ok_or_else(|| {
+        let expected = format!("the opponent's shape, {}", letter_list(opponent));
+        ParseError::in_line(1, line, &expected)
+    })?;
+    if letter(1) != Some(' ') {
+        return Err(ParseError::in_line(2, rest(1), "a space"));
+    }
+    let second = letter(2).and_then(|c| lookup(response, c)).ok_or_else(|| {
+        let expected = format!("a key, {}", letter_list(response));
+        ParseError::in_line(3, rest(2), &expected)
+    })?;
+    if chars.len() > 3 {
+        return Err(ParseError::in_line(4, rest(3), "the end of the line"));
+    }
+
+    Ok((first, second))
+}
+
+fn lookup<T: Copy>(letters: &[(char, T)], letter: char) -> Option<T> {
+    letters.iter().find(|(c, _)| *c == letter).map(|(_, meaning)| *meaning)
+}
+
+/// Like "`A`, `B` or `C`"
+fn letter_list<T>(letters: &[(char, T)]) -> String {
+    let quoted = letters.iter().map(|(c, _)| format!("`{}`", c)).collect::<Vec<String>>();
+    match quoted.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+/// Written like `ABC:XYZ:shapes` or `ABC:LDW:outcomes`: the opponent's letters for rock, paper
+/// and scissors, then ours, in the order rock, paper, scissors or lose, draw, win
+impl FromStr for Interpretation {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Interpretation, String> {
+        let usage = || format!("`{}` isn't like `ABC:XYZ:shapes` or `ABC:XYZ:outcomes`", text);
+        let parts = text.split(':').collect::<Vec<&str>>();
+        let [opponent, response, meaning] = parts[..] else {
+            return Err(usage());
+        };
+        let three = |letters: &str| -> Result<[char; 3], String> {
+            letters.chars().collect::<Vec<char>>().try_into().map_err(|_| usage())
+        };
+        let outcomes = match meaning {
+            "shapes" => false,
+            "outcomes" => true,
+            _ => return Err(usage()),
+        };
+
+        Interpretation::with_letters(three(opponent)?, three(response)?, outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate_scores;
+
+    static TEST_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn given_standard_interpretations_scores_match_both_parts() {
+        let as_shapes = Interpretation::shapes().parse(TEST_INPUT).unwrap();
+        let as_outcomes = Interpretation::outcomes().parse(TEST_INPUT).unwrap();
+        assert_eq!(calculate_scores(&as_shapes), 15);
+        assert_eq!(calculate_scores(&as_outcomes), 12);
+    }
+
+    #[test]
+    fn given_custom_letters_from_str_reads_the_guide_with_them() {
+        let interpretation = "RPS:LDW:outcomes".parse::<Interpretation>().unwrap();
+        let result = interpretation.parse("R D\nP L\nS W").unwrap();
+        assert_eq!(calculate_scores(&result), 12);
+    }
+
+    #[test]
+    fn given_mixed_responses_rounds_use_each_letters_meaning() {
+        let interpretation = Interpretation::new(
+            vec![('A', Shape::Rock), ('B', Shape::Paper), ('C', Shape::Scissors)],
+            vec![('r', Response::Shape(Shape::Rock)), ('w', Response::Outcome(Outcome::Win))],
+        )
+        .unwrap();
+        let result = interpretation.parse("A w\nB r").unwrap();
+        assert_eq!(result[0].me(), Shape::Paper);
+        assert_eq!(calculate_scores(&result), 8 + 1);
+    }
+
+    #[test]
+    fn given_letter_outside_the_interpretation_parse_lists_the_ones_it_knows() {
+        let interpretation = "RPS:LDW:outcomes".parse::<Interpretation>().unwrap();
+        let result = interpretation.parse("R D\nR X");
+        assert_eq!(result, Err(ParseError::new(2, 3, "X", "a key, `L`, `D` or `W`")));
+    }
+
+    #[test]
+    fn given_malformed_lines_parse_returns_error_at_the_problem() {
+        let result = Interpretation::shapes().parse("A Y\nD X");
+        assert_eq!(
+            result,
+            Err(ParseError::new(2, 1, "D X", "the opponent's shape, `A`, `B` or `C`"))
+        );
+        assert_eq!(
+            Interpretation::shapes().parse("AY"),
+            Err(ParseError::new(1, 2, "Y", "a space"))
+        );
+        assert!(Interpretation::shapes().parse("A ").is_err());
+        assert!(Interpretation::shapes().parse("A YY").is_err());
+    }
+
+    #[test]
+    fn given_bad_specs_from_str_returns_errors() {
+        assert!("ABC:XYZ".parse::<Interpretation>().is_err());
+        assert!("ABC:XY:shapes".parse::<Interpretation>().is_err());
+        assert!("ABC:XYZ:moves".parse::<Interpretation>().is_err());
+        assert_eq!(
+            "ABA:XYZ:shapes".parse::<Interpretation>(),
+            Err("`A` means two things in the first column".to_string())
+        );
+        assert!("ABC:X Z:shapes".parse::<Interpretation>().is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+
+use aoc_core::{ParseError, Solution};
+use interpretation::SHAPES;
+use lazy_static::lazy_static;
+
+mod analysis;
+mod generate;
+mod interpretation;
+mod rules;
+mod simulate;
+mod trace;
+
+pub use analysis::{analyse, Analysis, Mapping, Scored};
+pub use generate::generate;
+pub use interpretation::Interpretation;
+pub use rules::{RuleShape, Rules};
+pub use simulate::{
+    play, FrequencyCounter, Markov, Random, Record, Replay, Strategy, WinStayLoseShift,
+};
+pub use trace::{trace, Step, Trace};
+
+pub struct Day2;
+
+lazy_static! {
+    /// The puzzle's game, which says what each `Shape` is worth and which it beats
+    static ref RULES: Rules = Rules::rock_paper_scissors();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// What the second column of the guide tells us: either the shape to throw,
+/// or how the round needs to end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Response {
+    Shape(Shape),
+    Outcome(Outcome),
+}
+
+/// One line of the strategy guide: the opponent's shape, and what to do about it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    opponent: Shape,
+    response: Response,
+}
+
+/// The guide read both ways: with the second column as shapes for part 1,
+/// and as outcomes for part 2
+#[derive(Debug, PartialEq, Eq)]
+pub struct Guide {
+    pub as_shapes: Vec<Round>,
+    pub as_outcomes: Vec<Round>,
+}
+
+impl Shape {
+    /// Where this shape is in [`Rules::rock_paper_scissors`]
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn score(self) -> u32 {
+        RULES.shapes()[self.index()].points
+    }
+
+    /// The shape this one beats
+    pub fn beats(self) -> Shape {
+        SHAPES
+            .into_iter()
+            .find(|other| RULES.beats(self.index(), other.index()))
+            .unwrap()
+    }
+
+    /// The shape that beats this one
+    pub fn loses_to(self) -> Shape {
+        SHAPES
+            .into_iter()
+            .find(|other| RULES.beats(other.index(), self.index()))
+            .unwrap()
+    }
+
+    /// How throwing this shape against `opponent` turns out
+    pub fn outcome_against(self, opponent: Shape) -> Outcome {
+        RULES.outcome(self.index(), opponent.index())
+    }
+
+    /// What to throw against this shape to get `outcome`
+    pub fn shape_for_outcome(self, outcome: Outcome) -> Shape {
+        SHAPES[RULES.shape_for_outcome(self.index(), outcome).unwrap()]
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+impl Round {
+    pub fn new(opponent: Shape, response: Response) -> Round {
+        Round { opponent, response }
+    }
+
+    /// The shape we throw: the one we were told to, or the one that ends the round as we were told
+    pub fn me(&self) -> Shape {
+        match self.response {
+            Response::Shape(shape) => shape,
+            Response::Outcome(outcome) => self.opponent.shape_for_outcome(outcome),
+        }
+    }
+
+    pub fn opponent(&self) -> Shape {
+        self.opponent
+    }
+
+    pub fn response(&self) -> Response {
+        self.response
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        self.me().outcome_against(self.opponent)
+    }
+
+    /// Our score for the round: the points for our shape plus the points for the outcome
+    pub fn score(&self) -> u32 {
+        self.me().score() + self.outcome().score()
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Shape::Rock => "rock",
+            Shape::Paper => "paper",
+            Shape::Scissors => "scissors",
+        };
+        f.pad(name)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        f.pad(name)
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Shape(shape) => shape.fmt(f),
+            Response::Outcome(outcome) => outcome.fmt(f),
+        }
+    }
+}
+
+impl Solution for Day2 {
+    type Input<'a> = Guide;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Guide, ParseError> {
+        Ok(Guide {
+            as_shapes: Interpretation::shapes().parse(input)?,
+            as_outcomes: Interpretation::outcomes().parse(input)?,
+        })
+    }
+
+    fn part1(guide: &Guide) -> u32 {
+        calculate_scores(&guide.as_shapes)
+    }
+
+    fn part2(guide: &Guide) -> u32 {
+        calculate_scores(&guide.as_outcomes)
+    }
+}
+
+/// Totals the score of every round
+pub fn calculate_scores(rounds: &[Round]) -> u32 {
+    rounds.iter().map(Round::score).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = r###"A Y
+B X
+C Z"###;
+
+    #[test]
+    fn given_test_input_get_fifteen() {
+        let rounds = Day2::parse(TEST_INPUT).unwrap();
+        let result = Day2::part1(&rounds);
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    fn given_test_input_get_twelve() {
+        let rounds = Day2::parse(TEST_INPUT).unwrap();
+        let result = Day2::part2(&rounds);
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn given_test_input_parse_returns_rounds_both_ways() {
+        let result = Day2::parse(TEST_INPUT).unwrap();
+        let expected = vec![
+            Round::new(Shape::Rock, Response::Shape(Shape::Paper)),
+            Round::new(Shape::Paper, Response::Shape(Shape::Rock)),
+            Round::new(Shape::Scissors, Response::Shape(Shape::Scissors)),
+        ];
+        assert_eq!(result.as_shapes, expected);
+        let draw = Round::new(Shape::Rock, Response::Outcome(Outcome::Draw));
+        assert_eq!(result.as_outcomes[0], draw);
+    }
+
+    #[test]
+    fn given_each_shape_beats_and_loses_to_are_opposites() {
+        for shape in [Shape::Rock, Shape::Paper, Shape::Scissors] {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.loses_to().beats(), shape);
+            assert_eq!(shape.beats().outcome_against(shape), Outcome::Lose);
+            assert_eq!(shape.loses_to().outcome_against(shape), Outcome::Win);
+            assert_eq!(shape.outcome_against(shape), Outcome::Draw);
+        }
+    }
+
+    #[test]
+    fn given_rock_and_wind_get_paper() {
+        let result = Shape::Rock.shape_for_outcome(Outcome::Win);
+        assert_eq!(result, Shape::Paper);
+    }
+
+    #[test]
+    fn given_rock_and_lose_get_scissors() {
+        let result = Shape::Rock.shape_for_outcome(Outcome::Lose);
+        assert_eq!(result, Shape::Scissors);
+    }
+
+    #[test]
+    fn given_rock_and_draw_get_rock() {
+        let result = Shape::Rock.shape_for_outcome(Outcome::Draw);
+        assert_eq!(result, Shape::Rock);
+    }
+
+    #[test]
+    fn given_scissors_and_win_get_rock() {
+        let result = Shape::Scissors.shape_for_outcome(Outcome::Win);
+        assert_eq!(result, Shape::Rock);
+    }
+
+    #[test]
+    fn given_paper_against_rock_round_score_is_eight() {
+        let round = Round::new(Shape::Rock, Response::Shape(Shape::Paper));
+        assert_eq!(round.score(), 8);
+    }
+
+    #[test]
+    fn given_lose_against_paper_round_throws_rock_and_scores_one() {
+        let round = Round::new(Shape::Paper, Response::Outcome(Outcome::Lose));
+        assert_eq!(round.me(), Shape::Rock);
+        assert_eq!(round.score(), 1);
+    }
+}
@@ -0,0 +1,165 @@
+use std::{env, path::PathBuf, process::ExitCode};
+
+use aoc_core::{day_input_source_from, Solution};
+use day2::{
+    analyse, calculate_scores, play, trace, Day2, FrequencyCounter, Guide, Interpretation, Markov,
+    Random, Replay, Rules, Strategy, WinStayLoseShift,
+};
+
+static USAGE: &str = "usage: day2 [--interpret ABC:XYZ:shapes|outcomes] [--explain [--csv]] \
+                      [--audit | --simulate ROUNDS | --rules rps|rpsls] [INPUT]";
+
+fn main() -> ExitCode {
+    // `--interpret` scores the guide just one way, with whatever letters it is written in, and
+    // `--audit` scores it every way `X`, `Y` and `Z` could be read. `--simulate` plays the
+    // guide and some other strategies against each other. `--explain` shows every round, read
+    // the `--interpret` way or else the part 2 way, as a table or with `--csv` as CSV. `--rules`
+    // answers both parts under another game's rules
+    let mut args = env::args_os().skip(1);
+    let mut interpretation = None;
+    let mut audit = false;
+    let mut simulate = None;
+    let mut explain = false;
+    let mut csv = false;
+    let mut rules = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        if arg == "--audit" {
+            audit = true;
+        } else if arg == "--explain" {
+            explain = true;
+        } else if arg == "--csv" {
+            csv = true;
+        } else if arg == "--simulate" {
+            match args.next().and_then(|rounds| rounds.to_str()?.parse::<usize>().ok()) {
+                Some(rounds) => simulate = Some(rounds),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else if arg == "--rules" {
+            match args.next().as_ref().and_then(|name| rules_named(name.to_str()?)) {
+                Some(r) => rules = Some(r),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else if arg == "--interpret" {
+            let spec = args.next().map(|spec| spec.to_string_lossy().parse::<Interpretation>());
+            match spec {
+                Some(Ok(i)) => interpretation = Some(i),
+                Some(Err(e)) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else if input.is_none() {
+            input = Some(PathBuf::from(arg));
+        } else {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    }
+    let modes = [
+        audit,
+        interpretation.is_some() || explain,
+        simulate.is_some(),
+        rules.is_some(),
+    ];
+    if modes.iter().filter(|m| **m).count() > 1 || (csv && !explain) {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let source = day_input_source_from(2, env!("CARGO_MANIFEST_DIR"), input.as_deref());
+    let text = match source.read() {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = if audit {
+        analyse(&text).map(|analysis| analysis.to_string())
+    } else if let Some(rounds) = simulate {
+        Day2::parse(&text).map(|guide| tournament(&guide, rounds))
+    } else if explain {
+        let interpretation = interpretation.unwrap_or_else(Interpretation::outcomes);
+        interpretation.parse(&text).map(|rounds| {
+            let trace = trace(&rounds);
+            if csv {
+                trace.to_csv()
+            } else {
+                trace.to_string()
+            }
+        })
+    } else if let Some(rules) = rules {
+        rules.score_guide(&text).and_then(|part1| {
+            let part2 = rules.score_outcome_guide(&text)?;
+            Ok(format!("{}, {}", part1, part2))
+        })
+    } else if let Some(i) = interpretation {
+        i.parse(&text).map(|rounds| calculate_scores(&rounds).to_string())
+    } else {
+        Day2::parse(&text).map(|guide| format!("{}, {}", Day2::part1(&guide), Day2::part2(&guide)))
+    };
+    match result {
+        Ok(answer) => {
+            println!("{}", answer.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}: {}", source, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn rules_named(name: &str) -> Option<Rules> {
+    match name {
+        "rps" => Some(Rules::rock_paper_scissors()),
+        "rpsls" => Some(Rules::rock_paper_scissors_lizard_spock()),
+        _ => None,
+    }
+}
+
+/// Every strategy against every other for `rounds` rounds, one game to a line
+fn tournament(guide: &Guide, rounds: usize) -> String {
+    let entrants: Vec<Box<dyn Fn() -> Box<dyn Strategy> + '_>> = vec![
+        Box::new(|| Box::new(Replay::ours(&guide.as_shapes))),
+        Box::new(|| Box::new(Replay::opponents(&guide.as_shapes))),
+        Box::new(|| Box::new(Random::new(2022))),
+        Box::new(|| Box::new(FrequencyCounter::default())),
+        Box::new(|| Box::new(WinStayLoseShift::default())),
+        Box::new(|| Box::new(Markov::default())),
+    ];
+
+    let mut lines = vec![format!(
+        "{:<10}  {:<10}  {:>6}  {:>6}  {:>6}  {:>10}  {:>10}",
+        "player", "against", "won", "drew", "lost", "points", "against"
+    )];
+    for (i, a) in entrants.iter().enumerate() {
+        for b in entrants[i + 1..].iter() {
+            let (mut a, mut b) = (a(), b());
+            let record = play(a.as_mut(), b.as_mut(), rounds);
+            lines.push(format!(
+                "{:<10}  {:<10}  {:>5.1}%  {:>5.1}%  {:>5.1}%  {:>10}  {:>10}",
+                a.name(),
+                b.name(),
+                record.win_rate() * 100.0,
+                record.draw_rate() * 100.0,
+                record.loss_rate() * 100.0,
+                record.points,
+                record.opponent_points
+            ));
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,375 @@
+use aoc_core::{parse_lines, ParseError};
+
+use crate::{
+    interpretation::{parse_letters, OUTCOMES},
+    Outcome,
+};
+
+/// The letters for losing, drawing and winning when a guide's second column is outcomes
+const OUTCOME_LETTERS: [char; 3] = ['X', 'Y', 'Z'];
+
+/// One shape in a game: what it's called, the letters for it in each column of a guide,
+/// and the points for throwing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleShape {
+    pub name: String,
+    pub opponent_letter: char,
+    pub our_letter: char,
+    pub points: u32,
+}
+
+impl RuleShape {
+    pub fn new(name: &str, opponent_letter: char, our_letter: char, points: u32) -> RuleShape {
+        RuleShape {
+            name: name.to_string(),
+            opponent_letter,
+            our_letter,
+            points,
+        }
+    }
+}
+
+/// A game like rock paper scissors, as data: the shapes, and which of them beats which.
+/// Shapes are referred to by where they are in the list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<RuleShape>,
+    /// `beats[a][b]` when shape `a` beats shape `b`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// The `beats` pairs are (winner, loser) by name. They have to make a tournament:
+    /// of any two different shapes, exactly one beats the other
+    pub fn new(shapes: Vec<RuleShape>, beats: &[(&str, &str)]) -> Result<Rules, String> {
+        for (i, shape) in shapes.iter().enumerate() {
+            let earlier = &shapes[..i];
+            if earlier.iter().any(|s| s.name == shape.name) {
+                return Err(format!("`{}` is listed twice", shape.name));
+            }
+            if let Some(s) = earlier
+                .iter()
+                .find(|s| s.opponent_letter == shape.opponent_letter)
+            {
+                return Err(format!(
+                    "`{}` and `{}` have the same opponent letter",
+                    s.name, shape.name
+                ));
+            }
+            if let Some(s) = earlier.iter().find(|s| s.our_letter == shape.our_letter) {
+                return Err(format!(
+                    "`{}` and `{}` have the same letter",
+                    s.name, shape.name
+                ));
+            }
+        }
+
+        let index = |name: &str| {
+            shapes
+                .iter()
+                .position(|s| s.name == name)
+                .ok_or(format!("`{}` isn't one of the shapes", name))
+        };
+        let mut relation = vec![vec![false; shapes.len()]; shapes.len()];
+        for (winner, loser) in beats {
+            let (w, l) = (index(winner)?, index(loser)?);
+            if w == l {
+                return Err(format!("`{}` can't beat itself", winner));
+            }
+            relation[w][l] = true;
+        }
+
+        for a in 0..shapes.len() {
+            for b in a + 1..shapes.len() {
+                let (a_name, b_name) = (&shapes[a].name, &shapes[b].name);
+                match (relation[a][b], relation[b][a]) {
+                    (true, true) => {
+                        return Err(format!("`{}` and `{}` beat each other", a_name, b_name))
+                    }
+                    (false, false) => {
+                        return Err(format!(
+                            "neither `{}` nor `{}` beats the other",
+                            a_name, b_name
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Rules {
+            shapes,
+            beats: relation,
+        })
+    }
+
+    /// The balanced game of any odd number of shapes: each beats the half of the others
+    /// listed just before it, going round to the end of the list from the start
+    pub fn cyclic(shapes: Vec<RuleShape>) -> Result<Rules, String> {
+        let n = shapes.len();
+        if n.is_multiple_of(2) {
+            return Err(format!(
+                "a balanced game needs an odd number of shapes, not {}",
+                n
+            ));
+        }
+
+        let mut beats = Vec::new();
+        for (i, winner) in shapes.iter().enumerate() {
+            for step in 1..=n / 2 {
+                beats.push((winner.name.clone(), shapes[(i + n - step) % n].name.clone()));
+            }
+        }
+        let beats = beats
+            .iter()
+            .map(|(w, l)| (w.as_str(), l.as_str()))
+            .collect::<Vec<_>>();
+        Rules::new(shapes, &beats)
+    }
+
+    /// The puzzle's own game, with the usual letters
+    pub fn rock_paper_scissors() -> Rules {
+        Rules::cyclic(vec![
+            RuleShape::new("rock", 'A', 'X', 1),
+            RuleShape::new("paper", 'B', 'Y', 2),
+            RuleShape::new("scissors", 'C', 'Z', 3),
+        ])
+        .unwrap()
+    }
+
+    /// Rock paper scissors lizard Spock, where lizard is `D`/`W` and Spock is `E`/`V`
+    pub fn rock_paper_scissors_lizard_spock() -> Rules {
+        let shapes = vec![
+            RuleShape::new("rock", 'A', 'X', 1),
+            RuleShape::new("paper", 'B', 'Y', 2),
+            RuleShape::new("scissors", 'C', 'Z', 3),
+            RuleShape::new("lizard", 'D', 'W', 4),
+            RuleShape::new("spock", 'E', 'V', 5),
+        ];
+        let beats = [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ];
+        Rules::new(shapes, &beats).unwrap()
+    }
+
+    pub fn shapes(&self) -> &[RuleShape] {
+        &self.shapes
+    }
+
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.beats[a][b]
+    }
+
+    /// Whether every shape beats as many others as it loses to, so none is better than another
+    pub fn is_balanced(&self) -> bool {
+        self.beats
+            .iter()
+            .all(|row| row.iter().filter(|b| **b).count() * 2 + 1 == self.shapes.len())
+    }
+
+    pub fn outcome(&self, me: usize, opponent: usize) -> Outcome {
+        if me == opponent {
+            Outcome::Draw
+        } else if self.beats(me, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// The shape to throw against `opponent` for `outcome`, and when several would do, the one
+    /// worth the most points. None when no shape gets that outcome, like a win against a shape
+    /// that beats every other
+    pub fn shape_for_outcome(&self, opponent: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|me| self.outcome(*me, opponent) == outcome)
+            .max_by_key(|me| self.shapes[*me].points)
+    }
+
+    /// The points for our shape plus the points for the outcome
+    pub fn score(&self, me: usize, opponent: usize) -> u32 {
+        self.shapes[me].points + self.outcome(me, opponent).score()
+    }
+
+    /// Each line of the guide as the (opponent, us) shapes it names
+    pub fn parse(&self, input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        let opponent = self
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.opponent_letter, i))
+            .collect::<Vec<(char, usize)>>();
+        let ours = self
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.our_letter, i))
+            .collect::<Vec<(char, usize)>>();
+        parse_lines(input, |line| parse_letters(line, &opponent, &ours))
+    }
+
+    /// The total score for following the guide under these rules
+    pub fn score_guide(&self, input: &str) -> Result<u32, ParseError> {
+        let rounds = self.parse(input)?;
+        Ok(rounds
+            .iter()
+            .map(|(opponent, me)| self.score(*me, *opponent))
+            .sum())
+    }
+
+    /// Each line of a guide whose second column is `X`, `Y` or `Z` to lose, draw or win, as the
+    /// (opponent, us) shapes it calls for
+    pub fn parse_outcomes(&self, input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        let opponent = self
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.opponent_letter, i))
+            .collect::<Vec<(char, usize)>>();
+        let outcomes = OUTCOME_LETTERS
+            .into_iter()
+            .zip(OUTCOMES)
+            .collect::<Vec<(char, Outcome)>>();
+        parse_lines(input, |line| {
+            let (them, outcome) = parse_letters(line, &opponent, &outcomes)?;
+            let me = self.shape_for_outcome(them, outcome).ok_or_else(|| {
+                let name = &self.shapes[them].name;
+                let expected = format!("an outcome some shape gets against `{}`", name);
+                let key = line.char_indices().nth(2).map_or("", |(i, _)| &line[i..]);
+                ParseError::in_line(3, key, &expected)
+            })?;
+            Ok((them, me))
+        })
+    }
+
+    /// The total score for following a guide of outcomes under these rules
+    pub fn score_outcome_guide(&self, input: &str) -> Result<u32, ParseError> {
+        let rounds = self.parse_outcomes(input)?;
+        Ok(rounds
+            .iter()
+            .map(|(opponent, me)| self.score(*me, *opponent))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, Day2};
+    use aoc_core::Solution;
+
+    fn shapes(names: &[&str]) -> Vec<RuleShape> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                RuleShape::new(name, (b'a' + i as u8) as char, (b'A' + i as u8) as char, 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn given_generated_guide_rock_paper_scissors_rules_match_part_1() {
+        let input = generate(500, 3);
+        let expected = Day2::part1(&Day2::parse(&input).unwrap());
+        let result = Rules::rock_paper_scissors().score_guide(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_generated_guide_rock_paper_scissors_outcome_rules_match_part_2() {
+        let input = generate(500, 3);
+        let expected = Day2::part2(&Day2::parse(&input).unwrap());
+        let result = Rules::rock_paper_scissors().score_outcome_guide(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_rpsls_outcome_guide_picks_the_best_shape_for_each_outcome() {
+        // rock is beaten by paper and spock, so spock: 5 + 6. Lizard beats paper and spock, so
+        // spock again: 5 + 0. A draw against spock is spock: 5 + 3
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let result = rules.score_outcome_guide("A Z\nD X\nE Y");
+        assert_eq!(result, Ok(11 + 5 + 8));
+    }
+
+    #[test]
+    fn given_outcome_no_shape_can_get_parse_outcomes_returns_error() {
+        let rules = Rules::new(shapes(&["a", "b"]), &[("a", "b")]).unwrap();
+        let result = rules.parse_outcomes("a Y\na Z");
+        let expected = "an outcome some shape gets against `a`";
+        assert_eq!(result, Err(ParseError::new(2, 3, "Z", expected)));
+    }
+
+    #[test]
+    fn given_rpsls_every_shape_beats_two_and_loses_to_two() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        assert!(rules.is_balanced());
+        assert_eq!(rules.outcome(4, 0), Outcome::Win);
+        assert_eq!(rules.outcome(3, 4), Outcome::Win);
+        assert_eq!(rules.outcome(0, 4), Outcome::Lose);
+    }
+
+    #[test]
+    fn given_rpsls_guide_score_guide_adds_shape_and_outcome_points() {
+        // spock vaporizes rock: 5 + 6, lizard against lizard: 4 + 3, rock against paper: 1 + 0
+        let result = Rules::rock_paper_scissors_lizard_spock().score_guide("A V\nD W\nB X");
+        assert_eq!(result, Ok(11 + 7 + 1));
+    }
+
+    #[test]
+    fn given_seven_shapes_cyclic_returns_balanced_tournament() {
+        let rules = Rules::cyclic(shapes(&["a", "b", "c", "d", "e", "f", "g"])).unwrap();
+        assert!(rules.is_balanced());
+        assert!(rules.beats(1, 0));
+        assert!(rules.beats(0, 6));
+        assert!(rules.beats(0, 4));
+        assert!(!rules.beats(0, 3));
+    }
+
+    #[test]
+    fn given_even_number_of_shapes_cyclic_returns_error() {
+        assert!(Rules::cyclic(shapes(&["a", "b", "c", "d"])).is_err());
+    }
+
+    #[test]
+    fn given_unbalanced_tournament_new_accepts_it_but_it_isnt_balanced() {
+        let rules = Rules::new(
+            shapes(&["a", "b", "c"]),
+            &[("a", "b"), ("a", "c"), ("b", "c")],
+        )
+        .unwrap();
+        assert!(!rules.is_balanced());
+    }
+
+    #[test]
+    fn given_relations_that_arent_a_tournament_new_says_what_is_wrong() {
+        let three = || shapes(&["a", "b", "c"]);
+        assert_eq!(
+            Rules::new(three(), &[("a", "b"), ("b", "c")]),
+            Err("neither `a` nor `c` beats the other".to_string())
+        );
+        assert_eq!(
+            Rules::new(three(), &[("a", "b"), ("b", "a"), ("b", "c"), ("c", "a")]),
+            Err("`a` and `b` beat each other".to_string())
+        );
+        assert_eq!(
+            Rules::new(three(), &[("a", "a")]),
+            Err("`a` can't beat itself".to_string())
+        );
+        assert_eq!(
+            Rules::new(three(), &[("a", "z")]),
+            Err("`z` isn't one of the shapes".to_string())
+        );
+        assert!(Rules::new(shapes(&["a", "a"]), &[]).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{interpretation::SHAPES, Outcome, Round, Shape};
+
+/// A player in a simulated game, which can learn from how each round went
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    /// The shape to throw in the next round
+    fn choose(&mut self) -> Shape;
+
+    /// What we threw last round, and what the other player threw
+    fn observe(&mut self, _mine: Shape, _theirs: Shape) {}
+}
+
+/// Throws the same shapes as a guide, over and over, whatever the other player does.
+/// With no shapes at all it always throws rock
+pub struct Replay {
+    name: String,
+    shapes: Vec<Shape>,
+    next: usize,
+}
+
+/// Throws any shape, with the same chance of each
+pub struct Random {
+    rng: StdRng,
+}
+
+/// Throws what beats the shape the other player has thrown most so far
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: [usize; 3],
+}
+
+/// Throws the same again after a win, and otherwise what would have beaten the other player
+pub struct WinStayLoseShift {
+    next: Shape,
+}
+
+/// Guesses the other player's next shape from what they have thrown after their last one before,
+/// and throws what beats it
+#[derive(Default)]
+pub struct Markov {
+    /// `transitions[a][b]` is how often they threw `b` straight after `a`
+    transitions: [[usize; 3]; 3],
+    last: Option<Shape>,
+}
+
+/// How a game went for the first player
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub rounds: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub points: u64,
+    pub opponent_points: u64,
+}
+
+impl Replay {
+    pub fn new(name: &str, shapes: Vec<Shape>) -> Replay {
+        Replay {
+            name: name.to_string(),
+            shapes,
+            next: 0,
+        }
+    }
+
+    /// The shapes we throw in the guide
+    pub fn ours(rounds: &[Round]) -> Replay {
+        Replay::new("guide", rounds.iter().map(Round::me).collect())
+    }
+
+    /// The shapes the guide says the opponent will throw
+    pub fn opponents(rounds: &[Round]) -> Replay {
+        Replay::new("opponent", rounds.iter().map(Round::opponent).collect())
+    }
+}
+
+impl Strategy for Replay {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn choose(&mut self) -> Shape {
+        if self.shapes.is_empty() {
+            return Shape::Rock;
+        }
+        let shape = self.shapes[self.next % self.shapes.len()];
+        self.next += 1;
+        shape
+    }
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn choose(&mut self) -> Shape {
+        SHAPES[self.rng.random_range(0..3)]
+    }
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn choose(&mut self) -> Shape {
+        most_often(&self.counts).loses_to()
+    }
+
+    fn observe(&mut self, _mine: Shape, theirs: Shape) {
+        self.counts[theirs as usize] += 1;
+    }
+}
+
+impl Default for WinStayLoseShift {
+    fn default() -> WinStayLoseShift {
+        WinStayLoseShift { next: Shape::Rock }
+    }
+}
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self) -> &str {
+        "win-stay"
+    }
+
+    fn choose(&mut self) -> Shape {
+        self.next
+    }
+
+    fn observe(&mut self, mine: Shape, theirs: Shape) {
+        if mine.outcome_against(theirs) != Outcome::Win {
+            self.next = theirs.loses_to();
+        }
+    }
+}
+
+impl Strategy for Markov {
+    fn name(&self) -> &str {
+        "markov"
+    }
+
+    fn choose(&mut self) -> Shape {
+        match self.last {
+            Some(last) => most_often(&self.transitions[last as usize]).loses_to(),
+            None => Shape::Rock,
+        }
+    }
+
+    fn observe(&mut self, _mine: Shape, theirs: Shape) {
+        if let Some(last) = self.last {
+            self.transitions[last as usize][theirs as usize] += 1;
+        }
+        self.last = Some(theirs);
+    }
+}
+
+/// The shape counted most, or the earliest of them if more than one was
+fn most_often(counts: &[usize; 3]) -> Shape {
+    let most = (0..3).fold(0, |best, i| if counts[i] > counts[best] { i } else { best });
+    SHAPES[most]
+}
+
+/// Plays `rounds` rounds between `a` and `b`, scoring both the puzzle's way
+pub fn play(a: &mut dyn Strategy, b: &mut dyn Strategy, rounds: usize) -> Record {
+    let mut record = Record {
+        rounds,
+        ..Record::default()
+    };
+
+    for _ in 0..rounds {
+        let (mine, theirs) = (a.choose(), b.choose());
+        let outcome = mine.outcome_against(theirs);
+        match outcome {
+            Outcome::Win => record.wins += 1,
+            Outcome::Draw => record.draws += 1,
+            Outcome::Lose => record.losses += 1,
+        }
+        record.points += u64::from(mine.score() + outcome.score());
+        record.opponent_points += u64::from(theirs.score() + theirs.outcome_against(mine).score());
+
+        a.observe(mine, theirs);
+        b.observe(theirs, mine);
+    }
+
+    record
+}
+
+impl Record {
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.rounds == 0 {
+            0.0
+        } else {
+            count as f64 / self.rounds as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, Day2};
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_guide_replayed_against_its_opponent_play_scores_part_1() {
+        let guide = Day2::parse(&generate(400, 6)).unwrap();
+        let mut ours = Replay::ours(&guide.as_shapes);
+        let mut theirs = Replay::opponents(&guide.as_shapes);
+        let result = play(&mut ours, &mut theirs, 400);
+        assert_eq!(result.points, u64::from(Day2::part1(&guide)));
+        assert_eq!(result.wins + result.draws + result.losses, 400);
+    }
+
+    #[test]
+    fn given_one_shape_over_and_over_learning_strategies_beat_it() {
+        let strategies: [Box<dyn Strategy>; 3] = [
+            Box::new(FrequencyCounter::default()),
+            Box::new(WinStayLoseShift::default()),
+            Box::new(Markov::default()),
+        ];
+        for mut strategy in strategies {
+            let mut rock = Replay::new("rock", vec![Shape::Rock]);
+            let result = play(strategy.as_mut(), &mut rock, 100);
+            assert!(
+                result.wins >= 98,
+                "{} won {} of 100",
+                strategy.name(),
+                result.wins
+            );
+        }
+    }
+
+    #[test]
+    fn given_cycling_opponent_markov_learns_the_cycle() {
+        let mut markov = Markov::default();
+        let mut cycle = Replay::new("cycle", SHAPES.to_vec());
+        let result = play(&mut markov, &mut cycle, 300);
+        assert!(result.win_rate() > 0.95);
+    }
+
+    #[test]
+    fn given_same_seed_random_plays_the_same_game() {
+        let game = || play(&mut Random::new(3), &mut FrequencyCounter::default(), 200);
+        let result = game();
+        assert_eq!(result, game());
+        let total = result.win_rate() + result.draw_rate() + result.loss_rate();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn given_no_rounds_play_returns_empty_record() {
+        let result = play(&mut Random::new(1), &mut Markov::default(), 0);
+        assert_eq!(result, Record::default());
+        assert_eq!(result.win_rate(), 0.0);
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use crate::{Response, Round};
+
+/// One round of a guide, with the score so far
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub line: usize,
+    pub round: Round,
+    pub score: u32,
+    pub total: u32,
+}
+
+/// Every round of a guide in order, to see where a score comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+}
+
+/// The rounds are taken to be the guide's lines in order, from line 1
+pub fn trace(rounds: &[Round]) -> Trace {
+    let mut total = 0;
+    let steps = rounds
+        .iter()
+        .enumerate()
+        .map(|(i, round)| {
+            let score = round.score();
+            total += score;
+            Step {
+                line: i + 1,
+                round: *round,
+                score,
+                total,
+            }
+        })
+        .collect();
+
+    Trace { steps }
+}
+
+impl Trace {
+    pub fn total(&self) -> u32 {
+        self.steps.last().map_or(0, |step| step.total)
+    }
+
+    /// The same columns as the table, one round to a row after a header row
+    pub fn to_csv(&self) -> String {
+        let mut result = String::from("line,opponent,response,shape,why,outcome,score,total\n");
+        for step in self.steps.iter() {
+            let round = &step.round;
+            result.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                step.line,
+                round.opponent(),
+                round.response(),
+                round.me(),
+                why(round),
+                round.outcome(),
+                step.score,
+                step.total
+            ));
+        }
+        result
+    }
+}
+
+/// How we came to throw our shape: because the guide said to, or to get the outcome it asked for
+fn why(round: &Round) -> String {
+    match round.response() {
+        Response::Shape(_) => "the guide says so".to_string(),
+        Response::Outcome(outcome) => format!("to {} against {}", outcome, round.opponent()),
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whys = self
+            .steps
+            .iter()
+            .map(|step| why(&step.round))
+            .collect::<Vec<String>>();
+        let why_width = whys.iter().map(String::len).max().unwrap_or(0).max(3);
+        writeln!(
+            f,
+            "{:<6}  {:<8}  {:<8}  {:<8}  {:<why_width$}  {:<7}  {:<5}  total",
+            "line",
+            "opponent",
+            "response",
+            "shape",
+            "why",
+            "outcome",
+            "score",
+            why_width = why_width
+        )?;
+        for (step, why) in self.steps.iter().zip(whys.iter()) {
+            let round = &step.round;
+            writeln!(
+                f,
+                "{:<6}  {:<8}  {:<8}  {:<8}  {:<why_width$}  {:<7}  {:<5}  {}",
+                step.line,
+                round.opponent(),
+                round.response(),
+                round.me(),
+                why,
+                round.outcome(),
+                step.score,
+                step.total,
+                why_width = why_width
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_scores, generate, Interpretation};
+
+    static TEST_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn given_test_input_as_outcomes_trace_shows_each_round_and_running_total() {
+        let rounds = Interpretation::outcomes().parse(TEST_INPUT).unwrap();
+        let result = trace(&rounds);
+
+        let scores = result
+            .steps
+            .iter()
+            .map(|s| (s.score, s.total))
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![(4, 4), (1, 5), (7, 12)]);
+        assert_eq!(
+            result.to_csv(),
+            "line,opponent,response,shape,why,outcome,score,total\n\
+             1,rock,draw,rock,to draw against rock,draw,4,4\n\
+             2,paper,lose,rock,to lose against paper,lose,1,5\n\
+             3,scissors,win,rock,to win against scissors,win,7,12\n"
+        );
+    }
+
+    #[test]
+    fn given_test_input_as_shapes_trace_table_has_a_row_per_round() {
+        let rounds = Interpretation::shapes().parse(TEST_INPUT).unwrap();
+        let result = trace(&rounds).to_string();
+        let lines = result.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 4);
+        assert!(
+            lines[1].starts_with("1       rock      paper     paper     the guide says so  win")
+        );
+        assert!(lines[3].ends_with("  15"));
+    }
+
+    #[test]
+    fn given_generated_guide_trace_total_matches_calculate_scores() {
+        let rounds = Interpretation::outcomes().parse(&generate(300, 9)).unwrap();
+        assert_eq!(trace(&rounds).total(), calculate_scores(&rounds));
+        assert_eq!(trace(&[]).total(), 0);
+    }
+}
@@ -0,0 +1,91 @@
+use std::{collections::HashSet, hint::black_box};
+
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day3::{common_items, generate, Day3, ItemSet, GROUP_SIZE};
+
+/// Both parts with `ItemSet`, and nothing else: no checking the rucksacks, just building the
+/// sets and intersecting them
+fn item_set_priorities(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            let shared = ItemSet::from(left).intersection(ItemSet::from(right));
+            shared.priorities().next().unwrap_or(0)
+        })
+        .sum()
+}
+
+fn item_set_badge_priorities(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .chunks(GROUP_SIZE)
+        .map(|group| common_items(group).priorities().next().unwrap_or(0))
+        .sum()
+}
+
+/// Both parts the way they were before `ItemSet`: a `HashSet<char>` for every compartment and
+/// every rucksack in a group
+fn hash_set_priorities(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            let left = left.chars().collect::<HashSet<char>>();
+            right.chars().find(|c| left.contains(c)).map_or(0, priority)
+        })
+        .sum()
+}
+
+fn hash_set_badge_priorities(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .chunks(GROUP_SIZE)
+        .map(|group| {
+            let common = group
+                .iter()
+                .map(|rucksack| rucksack.chars().collect::<HashSet<char>>())
+                .reduce(|common, items| common.intersection(&items).copied().collect())
+                .unwrap();
+            common.into_iter().next().map_or(0, priority)
+        })
+        .sum()
+}
+
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+        _ => 0,
+    }
+}
+
+/// Each part on a million generated rucksacks, with `ItemSet` and with the `HashSet` it replaced,
+/// both on the same lines split up beforehand
+fn bench_item_sets(c: &mut Criterion) {
+    let input = generate(1_000_000, 1);
+    let rucksacks = input.lines().collect::<Vec<&str>>();
+    let parsed = Day3::parse(&input).unwrap();
+    assert_eq!(item_set_priorities(&rucksacks), Day3::part1(&parsed));
+    assert_eq!(hash_set_priorities(&rucksacks), Day3::part1(&parsed));
+    assert_eq!(item_set_badge_priorities(&rucksacks), Day3::part2(&parsed));
+    assert_eq!(hash_set_badge_priorities(&rucksacks), Day3::part2(&parsed));
+
+    let mut group = c.benchmark_group("day3");
+    group.sample_size(10);
+    group.bench_function("a/item_set", |b| {
+        b.iter(|| item_set_priorities(black_box(&rucksacks)))
+    });
+    group.bench_function("a/hash_set", |b| {
+        b.iter(|| hash_set_priorities(black_box(&rucksacks)))
+    });
+    group.bench_function("b/item_set", |b| {
+        b.iter(|| item_set_badge_priorities(black_box(&rucksacks)))
+    });
+    group.bench_function("b/hash_set", |b| {
+        b.iter(|| hash_set_badge_priorities(black_box(&rucksacks)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_item_sets);
+criterion_main!(benches);
@@ -0,0 +1,113 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` rucksacks (rounded up to whole groups of three), where each rucksack's compartments
+/// share exactly one item type, and each group of three shares exactly one badge
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = String::new();
+    for _ in 0..size.div_ceil(3) {
+        let badge = ITEMS[rng.random_range(0..ITEMS.len())];
+        // Every other item type is kept out of one of the three rucksacks, so only the badge
+        // can be common to all of them
+        let left_out_of = ITEMS
+            .iter()
+            .map(|_| rng.random_range(0..3))
+            .collect::<Vec<usize>>();
+        for elf in 0..3 {
+            let allowed = ITEMS
+                .iter()
+                .zip(left_out_of.iter())
+                .filter(|(item, left_out)| **item != badge && **left_out != elf)
+                .map(|(item, _)| *item)
+                .collect::<Vec<u8>>();
+            result.push_str(&rucksack(&mut rng, badge, &allowed));
+            result.push('\n');
+        }
+    }
+
+    result
+}
+
+fn rucksack(rng: &mut StdRng, badge: u8, allowed: &[u8]) -> String {
+    let shared = if rng.random_bool(0.1) {
+        badge
+    } else {
+        allowed[rng.random_range(0..allowed.len())]
+    };
+
+    // Apart from the shared item, each item type goes in one compartment or the other
+    let mut left_pool = Vec::new();
+    let mut right_pool = Vec::new();
+    for item in allowed.iter().filter(|item| **item != shared) {
+        if rng.random_bool(0.5) {
+            left_pool.push(*item);
+        } else {
+            right_pool.push(*item);
+        }
+    }
+
+    let mut left = vec![shared];
+    let mut right = vec![shared];
+    if shared != badge {
+        if rng.random_bool(0.5) {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+
+    let length = rng.random_range(4..=16);
+    fill_compartment(rng, &mut left, &left_pool, length);
+    fill_compartment(rng, &mut right, &right_pool, length);
+
+    left.append(&mut right);
+    String::from_utf8(left).unwrap()
+}
+
+fn fill_compartment(rng: &mut StdRng, compartment: &mut Vec<u8>, pool: &[u8], length: usize) {
+    while compartment.len() < length {
+        let item = if pool.is_empty() {
+            compartment[0]
+        } else {
+            pool[rng.random_range(0..pool.len())]
+        };
+        compartment.push(item);
+    }
+    compartment.shuffle(rng);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn common_items(rucksacks: &[&str]) -> HashSet<char> {
+        rucksacks
+            .iter()
+            .map(|r| r.chars().collect::<HashSet<char>>())
+            .reduce(|common, items| common.intersection(&items).copied().collect())
+            .unwrap()
+    }
+
+    #[test]
+    fn given_size_100_generate_returns_34_whole_groups() {
+        let input = generate(100, 1);
+        assert_eq!(input.lines().count(), 102);
+    }
+
+    #[test]
+    fn given_generated_input_every_rucksack_and_group_shares_exactly_one_item() {
+        let input = generate(300, 3);
+        let rucksacks = input.lines().collect::<Vec<&str>>();
+        for rucksack in rucksacks.iter() {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            assert_eq!(common_items(&[left, right]).len(), 1, "{}", rucksack);
+        }
+        for group in rucksacks.chunks(3) {
+            assert_eq!(common_items(group).len(), 1, "{:?}", group);
+        }
+    }
+}
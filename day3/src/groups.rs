@@ -0,0 +1,195 @@
+use aoc_core::ParseError;
+
+use crate::ItemSet;
+
+/// What to do with the rucksacks at the end of the input when there are too few for a whole group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trailing {
+    /// They are an error
+    Reject,
+    /// They are left out
+    Skip,
+    /// They are a smaller group, which still needs a badge
+    Partial,
+}
+
+/// The items in every one of `rucksacks`, or none at all if there are no rucksacks
+pub fn common_items(rucksacks: &[&str]) -> ItemSet {
+    rucksacks
+        .iter()
+        .map(|rucksack| ItemSet::from(*rucksack))
+        .reduce(ItemSet::intersection)
+        .unwrap_or_default()
+}
+
+/// The badge of each group of `group_size` rucksacks, where rucksack `i` is on line `i + 1`.
+/// A group with no item in common, or more than one, is an error.
+///
+/// Panics if `group_size` is 0
+pub fn find_badges(
+    rucksacks: &[&str],
+    group_size: usize,
+    trailing: Trailing,
+) -> Result<Vec<char>, ParseError> {
+    assert!(group_size > 0, "groups need at least one rucksack");
+
+    let mut badges = Vec::with_capacity(rucksacks.len() / group_size);
+    for (i, group) in rucksacks.chunks(group_size).enumerate() {
+        let first_line = i * group_size + 1;
+        if group.len() < group_size {
+            match trailing {
+                Trailing::Reject => {
+                    let expected = format!(
+                        "{} rucksacks in the last group, from line {}, not {}",
+                        group_size,
+                        first_line,
+                        group.len()
+                    );
+                    let last = group.len() - 1;
+                    return Err(ParseError::new(
+                        first_line + last,
+                        1,
+                        group[last],
+                        &expected,
+                    ));
+                }
+                Trailing::Skip => break,
+                Trailing::Partial => {}
+            }
+        }
+        badges.push(find_badge(group, first_line)?);
+    }
+
+    Ok(badges)
+}
+
+/// Finds the one item carried by every rucksack in the group, which starts on `first_line`
+fn find_badge(rucksacks: &[&str], first_line: usize) -> Result<char, ParseError> {
+    let common = common_items(rucksacks);
+    match common.len() {
+        1 => Ok(common.iter().next().unwrap()),
+        0 => {
+            // Point at the rucksack that left the group with nothing in common
+            let n = (1..=rucksacks.len())
+                .find(|n| common_items(&rucksacks[..*n]).is_empty())
+                .unwrap_or(rucksacks.len());
+            let line = first_line + n - 1;
+            let expected = if n == 1 {
+                "a rucksack with something in it".to_string()
+            } else if n == 2 {
+                format!("a rucksack sharing an item with line {}", first_line)
+            } else {
+                format!(
+                    "a rucksack sharing an item with lines {} to {}",
+                    first_line,
+                    line - 1
+                )
+            };
+            Err(ParseError::new(line, 1, rucksacks[n - 1], &expected))
+        }
+        _ => {
+            let expected = format!("a group with one item in common, not {}", common.quoted());
+            let last = rucksacks.len() - 1;
+            Err(ParseError::new(
+                first_line + last,
+                1,
+                rucksacks[last],
+                &expected,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_scenario_1_then_find_badge_returns_r() {
+        let binding = r###"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg"###
+            .lines()
+            .collect::<Vec<_>>();
+        let test_input = binding.as_slice();
+
+        let result = find_badge(test_input, 1);
+        assert_eq!(result, Ok('r'));
+    }
+
+    #[test]
+    fn given_scenario_2_then_find_badge_returns_upper_z() {
+        let binding = r###"vwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"###
+            .lines()
+            .collect::<Vec<_>>();
+        let test_input = binding.as_slice();
+
+        let result = find_badge(test_input, 1);
+        assert_eq!(result, Ok('Z'));
+    }
+
+    #[test]
+    fn given_groups_of_2_then_find_badges_returns_pair_badges() {
+        let rucksacks = ["abcX", "Xdef", "ghiY", "jklY"];
+        let result = find_badges(&rucksacks, 2, Trailing::Reject);
+        assert_eq!(result, Ok(vec!['X', 'Y']));
+    }
+
+    #[test]
+    fn given_five_rucksacks_common_items_intersects_all_of_them() {
+        let result = common_items(&["abcd", "bcde", "cbef", "xcyb", "bzc"]);
+        assert_eq!(result, ItemSet::from("bc"));
+        assert!(common_items(&[]).is_empty());
+    }
+
+    #[test]
+    fn given_trailing_pair_then_find_badges_does_what_it_is_told() {
+        let rucksacks = ["aZbc", "dZef", "Zghi", "jkZl", "mxn", "oxp"];
+        assert_eq!(find_badges(&rucksacks, 4, Trailing::Skip), Ok(vec!['Z']));
+        assert_eq!(
+            find_badges(&rucksacks, 4, Trailing::Partial),
+            Ok(vec!['Z', 'x'])
+        );
+        assert_eq!(
+            find_badges(&rucksacks, 4, Trailing::Reject),
+            Err(ParseError::new(
+                6,
+                1,
+                "oxp",
+                "4 rucksacks in the last group, from line 5, not 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_group_with_nothing_in_common_then_find_badges_points_at_the_odd_one_out() {
+        let rucksacks = ["abc", "aZc", "ayz", "Zbc", "def", "Zgh"];
+        let result = find_badges(&rucksacks, 3, Trailing::Reject);
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                5,
+                1,
+                "def",
+                "a rucksack sharing an item with line 4"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_group_with_two_items_in_common_then_find_badges_lists_them() {
+        let rucksacks = ["abc", "bca", "cxa", "dxe"];
+        let result = find_badges(&rucksacks, 3, Trailing::Skip);
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                3,
+                1,
+                "cxa",
+                "a group with one item in common, not `a`, `c`"
+            ))
+        );
+    }
+}
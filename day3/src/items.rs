@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::get_item_priority;
+
+/// A set of item types, as a bit for each priority from 1 to 52, so building, combining and
+/// comparing sets never allocates
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> ItemSet {
+        ItemSet(0)
+    }
+
+    /// Adds `item`, unless it isn't an item type at all, when it returns false
+    pub fn insert(&mut self, item: char) -> bool {
+        match get_item_priority(item) {
+            0 => false,
+            priority => {
+                self.0 |= 1 << priority;
+                true
+            }
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        match get_item_priority(item) {
+            0 => false,
+            priority => self.0 & (1 << priority) != 0,
+        }
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items in priority order: `a` to `z`, then `A` to `Z`
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        self.priorities().map(item_with_priority)
+    }
+
+    /// Like "`a`, `b`", for error messages
+    pub(crate) fn quoted(&self) -> String {
+        let items = self.iter().map(|c| format!("`{}`", c)).collect::<Vec<String>>();
+        items.join(", ")
+    }
+
+    /// The priority of each item, lowest first
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        let bits = self.0;
+        (1..=52).filter(move |priority| bits & (1 << priority) != 0)
+    }
+}
+
+fn item_with_priority(priority: u32) -> char {
+    let base = if priority <= 26 { b'a' - 1 } else { b'A' - 27 };
+    (base + priority as u8) as char
+}
+
+/// Leaves out any character that isn't an item type
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(items: I) -> ItemSet {
+        let mut set = ItemSet::new();
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl From<&str> for ItemSet {
+    fn from(items: &str) -> ItemSet {
+        items.chars().collect()
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_items_iter_returns_them_once_each_in_priority_order() {
+        let result = ItemSet::from("ZbaZzA");
+        assert_eq!(result.iter().collect::<String>(), "abzAZ");
+        assert_eq!(
+            result.priorities().collect::<Vec<u32>>(),
+            vec![1, 2, 26, 27, 52]
+        );
+        assert_eq!(result.len(), 5);
+    }
+
+    #[test]
+    fn given_two_sets_union_and_intersection_combine_them() {
+        let left = ItemSet::from("abcX");
+        let right = ItemSet::from("cXyz");
+        assert_eq!(left.intersection(right), ItemSet::from("cX"));
+        assert_eq!(left.union(right), ItemSet::from("abcyzX"));
+        assert!(left.intersection(ItemSet::from("q")).is_empty());
+    }
+
+    #[test]
+    fn given_character_that_isnt_an_item_insert_leaves_it_out() {
+        let mut set = ItemSet::new();
+        assert!(set.insert('q'));
+        assert!(!set.insert('1'));
+        assert!(!set.insert(' '));
+        assert!(set.contains('q'));
+        assert!(!set.contains('1'));
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,211 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+
+mod generate;
+mod groups;
+mod items;
+mod validate;
+
+pub use generate::generate;
+pub use groups::{common_items, find_badges, Trailing};
+pub use items::ItemSet;
+pub use validate::{validate, Validation};
+
+pub struct Day3;
+
+/// How many elves share a badge
+pub const GROUP_SIZE: usize = 3;
+
+/// The rucksacks in order, each with the one item type both its compartments hold, and the
+/// badge of each group of them
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rucksacks<'a> {
+    pub lines: Vec<&'a str>,
+    pub shared: Vec<char>,
+    pub badges: Vec<char>,
+}
+
+impl Solution for Day3 {
+    type Input<'a> = Rucksacks<'a>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Rucksacks<'_>, ParseError> {
+        let parsed = parse_lines(input, |line| find_shared_items(line).map(|item| (line, item)))?;
+        let (lines, shared): (Vec<&str>, Vec<char>) = parsed.into_iter().unzip();
+        let badges = find_badges(&lines, GROUP_SIZE, Trailing::Reject)?;
+        Ok(Rucksacks { lines, shared, badges })
+    }
+
+    fn part1(rucksacks: &Rucksacks<'_>) -> u32 {
+        rucksacks.shared.iter().map(|item| get_item_priority(*item)).sum()
+    }
+
+    fn part2(rucksacks: &Rucksacks<'_>) -> u32 {
+        rucksacks.badges.iter().map(|badge| get_item_priority(*badge)).sum()
+    }
+}
+
+/// The one item type in both halves of `rucksack`, which has to be an even number of items
+fn find_shared_items(rucksack: &str) -> Result<char, ParseError> {
+    let invalid = rucksack.chars().enumerate().find(|(_, c)| get_item_priority(*c) == 0);
+    if let Some((i, c)) = invalid {
+        let expected = "an item, `a` to `z` or `A` to `Z`";
+        return Err(ParseError::in_line(i + 1, &c.to_string(), expected));
+    }
+    if !rucksack.len().is_multiple_of(2) {
+        let expected = format!("an even number of items, not {}", rucksack.len());
+        return Err(ParseError::in_line(1, rucksack, &expected));
+    }
+
+    let split = rucksack.len() / 2;
+    let left = ItemSet::from(&rucksack[..split]);
+    let right = ItemSet::from(&rucksack[split..]);
+    let shared = left.intersection(right);
+    match shared.len() {
+        1 => Ok(shared.iter().next().unwrap()),
+        0 => Err(ParseError::in_line(1, rucksack, "compartments with an item type in common")),
+        _ => {
+            let expected =
+                format!("compartments with one item type in common, not {}", shared.quoted());
+            Err(ParseError::in_line(1, rucksack, &expected))
+        }
+    }
+}
+
+const ASCII_LOWER_A: u32 = 'a' as u32;
+const ASCII_LOWER_Z: u32 = 'z' as u32;
+const ASCII_UPPER_A: u32 = 'A' as u32;
+const ASCII_UPPER_Z: u32 = 'Z' as u32;
+
+pub(crate) fn get_item_priority(shared_item: char) -> u32 {
+    match shared_item as u32 {
+        n if (ASCII_LOWER_A..=ASCII_LOWER_Z).contains(&n) => (n - ASCII_LOWER_A) + 1,
+        n if (ASCII_UPPER_A..=ASCII_UPPER_Z).contains(&n) => (n - ASCII_UPPER_A) + 27,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::solve_part_b;
+
+    #[test]
+    fn given_test_input_then_get_157() {
+        let test_input = r###"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"###
+            .trim();
+
+        let rucksacks = Day3::parse(test_input).unwrap();
+        let result = Day3::part1(&rucksacks);
+        assert_eq!(result, 157);
+    }
+
+    #[test]
+    fn given_case_1_then_find_shared_items_returns_p() {
+        let test_input = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('p'));
+    }
+
+    #[test]
+    fn given_case_2_then_find_shared_items_returns_upper_l() {
+        let test_input = "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('L'));
+    }
+
+    #[test]
+    fn given_case_3_then_find_shared_items_returns_upper_p() {
+        let test_input = "PmmdzqPrVvPwwTWBwg";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('P'));
+    }
+
+    #[test]
+    fn given_case_4_then_find_shared_items_returns_v() {
+        let test_input = "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('v'));
+    }
+
+    #[test]
+    fn given_case_5_then_find_shared_items_returns_t() {
+        let test_input = "ttgJtRGJQctTZtZT";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('t'));
+    }
+
+    #[test]
+    fn given_case_6_then_find_shared_items_returns_s() {
+        let test_input = "CrZsJsPPZsGzwwsLwLmpwMDw";
+        let result = find_shared_items(test_input);
+        assert_eq!(result, Ok('s'));
+    }
+
+    #[test]
+    fn given_test_input_then_get_70() {
+        let test_input = r###"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"###
+            .trim();
+
+        let rucksacks = Day3::parse(test_input).unwrap();
+        let result = Day3::part2(&rucksacks);
+        assert_eq!(result, 70);
+    }
+
+    #[test]
+    fn given_rucksacks_left_over_then_parse_and_solve_part_b_return_error() {
+        let input = "abca\nbcdb\ncxyc\nxyzx";
+        let expected =
+            ParseError::new(4, 1, "xyzx", "3 rucksacks in the last group, from line 4, not 1");
+        assert_eq!(Day3::parse(input), Err(expected.clone()));
+        assert_eq!(solve_part_b::<Day3>(input), Err(expected));
+    }
+
+    #[test]
+    fn given_group_without_a_badge_then_parse_returns_error_on_the_odd_one_out() {
+        let result = Day3::parse("abca\nbcdb\nxyzx");
+        let expected = "a rucksack sharing an item with lines 1 to 2";
+        assert_eq!(result, Err(ParseError::new(3, 1, "xyzx", expected)));
+    }
+
+    #[test]
+    fn given_odd_length_rucksack_then_parse_returns_error_on_its_line() {
+        let result = Day3::parse("abca\nabc\ncxyc");
+        assert_eq!(result, Err(ParseError::new(2, 1, "abc", "an even number of items, not 3")));
+    }
+
+    #[test]
+    fn given_a_then_get_item_priority_returns_1() {
+        let result = get_item_priority('a');
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn given_z_then_get_item_priority_returns_26() {
+        let result = get_item_priority('z');
+        assert_eq!(result, 26);
+    }
+
+    #[test]
+    fn given_upper_a_then_get_item_priority_returns_27() {
+        let result = get_item_priority('A');
+        assert_eq!(result, 27);
+    }
+
+    #[test]
+    fn given_upper_z_then_get_item_priority_returns_52() {
+        let result = get_item_priority('Z');
+        assert_eq!(result, 52);
+    }
+}
@@ -0,0 +1,51 @@
+use std::{env, path::PathBuf, process::ExitCode};
+
+use aoc_core::{day_input_source_from, Solution};
+use day3::{validate, Day3};
+
+static USAGE: &str = "usage: day3 [--check] [INPUT]";
+
+fn main() -> ExitCode {
+    // `--check` warns about every bad rucksack instead of stopping at the first, and prints the
+    // part 1 answer for the rest
+    let mut check = false;
+    let mut input = None;
+    for arg in env::args_os().skip(1) {
+        if arg == "--check" {
+            check = true;
+        } else if input.is_none() && !arg.to_string_lossy().starts_with("--") {
+            input = Some(PathBuf::from(arg));
+        } else {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let source = day_input_source_from(3, env!("CARGO_MANIFEST_DIR"), input.as_deref());
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if check {
+        let validation = validate(&input);
+        for e in validation.problems.iter() {
+            eprintln!("warning: {}: skipped {}", source, e);
+        }
+        println!("{}", validation.priorities);
+        return ExitCode::SUCCESS;
+    }
+
+    let rucksacks = match Day3::parse(&input) {
+        Ok(rucksacks) => rucksacks,
+        Err(e) => {
+            eprintln!("error: {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{}, {}", Day3::part1(&rucksacks), Day3::part2(&rucksacks));
+    ExitCode::SUCCESS
+}
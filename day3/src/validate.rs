@@ -0,0 +1,84 @@
+use aoc_core::ParseError;
+
+use crate::{find_shared_items, get_item_priority};
+
+/// Every rucksack checked, carrying on past the bad ones instead of stopping at the first
+#[derive(Debug, PartialEq, Eq)]
+pub struct Validation {
+    /// The sum of the shared items' priorities, over just the rucksacks that are fine
+    pub priorities: u32,
+    pub valid: usize,
+    /// What is wrong with each of the others, in line order
+    pub problems: Vec<ParseError>,
+}
+
+/// Checks each line is a rucksack of items with an even number of them, whose compartments
+/// share exactly one item type
+pub fn validate(input: &str) -> Validation {
+    let mut validation = Validation {
+        priorities: 0,
+        valid: 0,
+        problems: Vec::new(),
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        match find_shared_items(line) {
+            Ok(item) => {
+                validation.priorities += get_item_priority(item);
+                validation.valid += 1;
+            }
+            Err(e) => validation.problems.push(e.offset_lines(i)),
+        }
+    }
+
+    validation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, Day3};
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_generated_input_validate_finds_no_problems_and_matches_part_1() {
+        let input = generate(300, 2);
+        let result = validate(&input);
+        assert!(result.problems.is_empty());
+        assert_eq!(result.valid, 300);
+        assert_eq!(
+            result.priorities,
+            Day3::part1(&Day3::parse(&input).unwrap())
+        );
+    }
+
+    #[test]
+    fn given_bad_rucksacks_validate_reports_each_and_sums_the_rest() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\nabc\nabcd\nabab\nab1b\nttgJtRGJQctTZtZT";
+        let result = validate(input);
+
+        assert_eq!(result.priorities, 16 + 20);
+        assert_eq!(result.valid, 2);
+        assert_eq!(
+            result.problems,
+            vec![
+                ParseError::new(2, 1, "abc", "an even number of items, not 3"),
+                ParseError::new(3, 1, "abcd", "compartments with an item type in common"),
+                ParseError::new(
+                    4,
+                    1,
+                    "abab",
+                    "compartments with one item type in common, not `a`, `b`"
+                ),
+                ParseError::new(5, 3, "1", "an item, `a` to `z` or `A` to `Z`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_multibyte_character_validate_reports_it_instead_of_splitting_it() {
+        let result = validate("aéba");
+        assert_eq!(result.problems[0].column, 2);
+        assert_eq!(result.problems[0].text, "é");
+    }
+}
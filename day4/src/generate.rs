@@ -0,0 +1,35 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` pairs of section ranges, all within sections 1 to 99
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = String::with_capacity(size * 12);
+    for _ in 0..size {
+        let mut range = || {
+            let start = rng.random_range(1..=99);
+            let end = rng.random_range(start..=99);
+            format!("{}-{}", start, end)
+        };
+        let left = range();
+        let right = range();
+        result.push_str(&format!("{},{}\n", left, right));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day4;
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_size_100_generate_returns_100_valid_pairs() {
+        let result = Day4::parse(&generate(100, 1)).unwrap();
+        assert_eq!(result.len(), 100);
+        assert!(result
+            .iter()
+            .all(|pair| pair.left.start() <= pair.left.end()));
+    }
+}
@@ -0,0 +1,273 @@
+use std::ops::RangeInclusive;
+use aoc_core::{parse_lines, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+pub use generate::generate;
+
+pub struct Day4;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RangePair {
+    left: RangeInclusive<u32>,
+    right: RangeInclusive<u32>,
+}
+
+impl RangePair {
+    fn is_fully_containing(&self) -> bool {
+        self.left.fully_contains(&self.right) || self.right.fully_contains(&self.left)
+    }
+}
+
+trait FullyContains<Rhs=Self> {
+    fn fully_contains(&self, rhs: &Rhs) -> bool;
+}
+
+
+impl FullyContains for RangeInclusive<u32> {
+    fn fully_contains(&self, rhs: &Self) -> bool {
+        self.start() <= rhs.start() && self.end() >= rhs.end()
+    }
+}
+
+trait Overlaps<Rhs=Self> {
+    fn overlaps(&self, rhs: &Rhs) -> bool;
+}
+
+impl Overlaps for RangeInclusive<u32> {
+    fn overlaps(&self, rhs: &Self) -> bool {
+        (self.start() <= rhs.start() && self.end() >= rhs.start())
+            || (rhs.start() <= self.start() && rhs.end() >= self.start())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ResultValues {
+    contained: u32,
+    overlaps: u32
+}
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<RangePair>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<RangePair>, ParseError> {
+        // Parse lines into pairs of ranges
+        parse_lines(input, line_to_ranges)
+    }
+
+    fn part1(range_pairs: &Vec<RangePair>) -> u32 {
+        count_fully_contained(range_pairs).contained
+    }
+
+    fn part2(range_pairs: &Vec<RangePair>) -> u32 {
+        count_fully_contained(range_pairs).overlaps
+    }
+}
+
+fn count_fully_contained(range_pairs: &[RangePair]) -> ResultValues {
+    let mut fully_contained_pairs = 0;
+    let mut overlaps = 0;
+    for range_pair in range_pairs.iter() {
+        // Compare ranges for overlap
+        // if overlap sum++
+        if range_pair.is_fully_containing() {
+            fully_contained_pairs += 1;
+        }
+
+        if range_pair.left.overlaps(&range_pair.right) {
+            overlaps += 1;
+        }
+    }
+    
+    ResultValues {
+        contained: fully_contained_pairs,
+        overlaps
+    }
+}
+
+
+fn line_to_ranges(line: &str) -> Result<RangePair, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+    }   
+
+    let captures = RE
+        .captures(line)
+        .ok_or_else(|| ParseError::in_line(1, line, "a pair of ranges like `2-4,6-8`"))?;
+    let section = |i: usize| {
+        let m = captures.get(i).unwrap();
+        m.as_str()
+            .parse::<u32>()
+            .map_err(|_| ParseError::in_line(m.start() + 1, m.as_str(), "a section number"))
+    };
+    // A range is sections `lower` to `upper`, and can't run backwards
+    let range = |lower: usize, upper: usize| {
+        let (start, end) = (section(lower)?, section(upper)?);
+        if start > end {
+            let from = captures.get(lower).unwrap().start();
+            let text = &line[from..captures.get(upper).unwrap().end()];
+            let expected = "a range whose start is no bigger than its end";
+            return Err(ParseError::in_line(from + 1, text, expected));
+        }
+        Ok(start..=end)
+    };
+
+    Ok(RangePair {
+        left: range(1, 2)?,
+        right: range(3, 4)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn sections(range: &RangeInclusive<u32>) -> HashSet<u32> {
+        range.clone().collect()
+    }
+
+    /// Ranges of up to ten sections, including single sections, all close enough to overlap often
+    fn range() -> impl Strategy<Value = RangeInclusive<u32>> {
+        (1..20u32, 0..10u32).prop_map(|(start, length)| start..=start + length)
+    }
+
+    #[test]
+    fn given_test_input_returns_2_4() {
+        let test_input = r###"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"###.trim();
+        let range_pairs = Day4::parse(test_input).unwrap();
+        let result = count_fully_contained(&range_pairs);
+
+        assert_eq!(result, ResultValues {
+            contained: 2,
+            overlaps: 4
+        });
+    }
+
+    #[test]
+    fn given_all_single_digits_then_returns_correct_pair() {
+        let test_input = r"1-2,3-4";
+        let expected = RangePair {
+            left: (1..=2),
+            right: (3..=4)
+        };
+
+        let result = line_to_ranges(test_input).unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn given_all_double_digits_then_returns_correct_pair() {
+        let test_input = r"11-12,13-14";
+        let expected = RangePair {
+            left: (11..=12),
+            right: (13..=14)
+        };
+
+        let result = line_to_ranges(test_input).unwrap();
+        assert_eq!(expected, result);
+
+    }
+
+    #[test]
+    fn given_missing_comma_parse_returns_error_on_that_line() {
+        let result = Day4::parse("1-2,3-4\n5-67-8");
+        assert_eq!(
+            result,
+            Err(ParseError::new(2, 1, "5-67-8", "a pair of ranges like `2-4,6-8`"))
+        );
+    }
+
+    #[test]
+    fn given_huge_section_line_to_ranges_returns_error_at_its_column() {
+        let result = line_to_ranges("1-2,3-99999999999");
+        assert_eq!(
+            result,
+            Err(ParseError::in_line(7, "99999999999", "a section number"))
+        );
+    }
+
+    #[test]
+    fn given_reversed_range_line_to_ranges_returns_error_at_that_range() {
+        let result = line_to_ranges("4-4,5-3");
+        assert_eq!(
+            result,
+            Err(ParseError::in_line(5, "5-3", "a range whose start is no bigger than its end"))
+        );
+        assert!(Day4::parse("5-3,4-4").is_err());
+    }
+
+    #[test]
+    fn given_fully_contained_ranges_return_true() {
+        let pair = RangePair {
+            left: (1..=5),
+            right: (2..=3),
+        };
+        let result = pair.is_fully_containing();
+        assert!(result);
+    }
+
+    #[test]
+    fn given_revers_fully_contained_ranges_is_fully_containing_returns_return_true() {
+        let pair = RangePair {
+            left: (2..=3),
+            right: (1..=5),
+        };
+        let result = pair.is_fully_containing();
+        assert!(result);
+    }
+
+    #[test]
+    fn given_mutually_exclusive_ranges_is_fully_containing_returns_false() {
+        let pair = RangePair {
+            left: (1..=2),
+            right: (3..=4)
+        };
+        let result = pair.is_fully_containing();
+        assert!(!result);
+    }
+
+    #[test]
+    fn given_overlapping_ranges_is_fully_containing_returns_false() {
+        let pair = RangePair {
+            left: (1..=3),
+            right: (2..=4)
+        };
+        let result = pair.is_fully_containing();
+        assert!(!result);
+    }
+
+    #[test]
+    fn given_right_higher_overlaps_left_lower_overlaps_returns_true() {
+        assert!((1..=3).overlaps(&(2..=4)));
+    }
+    
+    #[test]
+    fn given_right_lower_overlaps_left_higher_overlaps_returns_true() {
+        assert!((2..=4).overlaps(&(1..=3)));
+    }
+
+    proptest! {
+        #[test]
+        fn given_any_ranges_overlaps_matches_set_intersection(left in range(), right in range()) {
+            let expected = !sections(&left).is_disjoint(&sections(&right));
+            prop_assert_eq!(left.overlaps(&right), expected);
+            prop_assert_eq!(right.overlaps(&left), expected);
+        }
+
+        #[test]
+        fn given_any_ranges_fully_contains_matches_set_superset(left in range(), right in range()) {
+            let expected = sections(&left).is_superset(&sections(&right));
+            prop_assert_eq!(left.fully_contains(&right), expected);
+        }
+    }
+}
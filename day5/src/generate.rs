@@ -0,0 +1,91 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const STACK_COUNT: usize = 9;
+
+/// Nine stacks of crates and `size` moves. The moves are simulated as they are generated, so no
+/// move takes more crates than its stack holds, though a stack may end up empty
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let stacks = (0..STACK_COUNT)
+        .map(|_| {
+            let height = rng.random_range(2..=12);
+            (0..height)
+                .map(|_| char::from(b'A' + rng.random_range(0..26)))
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    let mut result = draw_stacks(&stacks);
+    result.push('\n');
+
+    let mut heights = stacks.iter().map(|s| s.len()).collect::<Vec<usize>>();
+    for _ in 0..size {
+        let sources = (0..STACK_COUNT)
+            .filter(|s| heights[*s] >= 1)
+            .collect::<Vec<usize>>();
+        let from = sources[rng.random_range(0..sources.len())];
+        let mut to = rng.random_range(0..STACK_COUNT - 1);
+        if to >= from {
+            to += 1;
+        }
+        let count = rng.random_range(1..=heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        result.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+    }
+
+    result
+}
+
+/// The drawing of the stacks from the top down, then the row of stack numbers
+fn draw_stacks(stacks: &[Vec<char>]) -> String {
+    let tallest = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut result = String::new();
+    for row in (0..tallest).rev() {
+        let crates = stacks
+            .iter()
+            .map(|s| match s.get(row) {
+                Some(letter) => format!("[{}]", letter),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<String>>();
+        result.push_str(&crates.join(" "));
+        result.push('\n');
+    }
+
+    let labels = (1..=stacks.len())
+        .map(|s| format!(" {} ", s))
+        .collect::<Vec<String>>();
+    result.push_str(&labels.join(" "));
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day5;
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_size_500_generate_returns_nine_stacks_and_500_moves() {
+        let input = generate(500, 1);
+        let supplies = Day5::parse(&input).unwrap();
+        assert_eq!(supplies.stacks.len(), 9);
+        assert_eq!(supplies.moves.len(), 500);
+    }
+
+    #[test]
+    fn given_generated_input_both_cranes_answer_for_every_stack() {
+        let input = generate(500, 2);
+        let supplies = Day5::parse(&input).unwrap();
+        assert_eq!(Day5::part1(&supplies).len(), 9);
+        assert_eq!(Day5::part2(&supplies).len(), 9);
+    }
+
+    #[test]
+    fn given_two_stacks_draw_stacks_pads_the_shorter_one() {
+        let result = draw_stacks(&[vec!['Z', 'N'], vec!['M']]);
+        assert_eq!(result, "[N]    \n[Z] [M]\n 1   2 \n");
+    }
+}
@@ -0,0 +1,530 @@
+use aoc_core::{ParseError, Solution};
+use regex::Regex;
+use lazy_static::lazy_static;
+
+mod generate;
+pub use generate::generate;
+
+pub struct Day5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct MoveInstruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct PuzzleInput<'a, 'b> {
+    stack_definition: &'b [&'a str],
+    moves_definition: &'b [&'a str],
+}
+
+/// How the crane moves a group of crates between stacks
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CraneModel {
+    /// Moves crates one at a time, so they land in reverse order
+    CrateMover9000,
+    /// Moves all the crates at once, so they keep their order
+    CrateMover9001,
+}
+
+/// The starting stacks of crates, and the moves the crane makes
+#[derive(Debug, PartialEq, Eq)]
+pub struct Supplies<'a> {
+    stacks: Vec<Vec<&'a str>>,
+    moves: Vec<MoveInstruction>,
+}
+
+impl Solution for Day5 {
+    type Input<'a> = Supplies<'a>;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Supplies<'_>, ParseError> {
+        let lines = input.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines)?;
+
+        // Parse stacks
+        let stacks = parse_stacks(puzzle_input.stack_definition)?;
+        // Parse movements, which start after the stacks and the blank line
+        let first_move_line = puzzle_input.stack_definition.len() + 1;
+        let moves = puzzle_input
+            .moves_definition
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                parse_move(row, stacks.len()).map_err(|e| e.offset_lines(first_move_line + i))
+            })
+            .collect::<Result<Vec<MoveInstruction>, ParseError>>()?;
+        check_moves(&stacks, &moves, first_move_line)?;
+
+        Ok(Supplies { stacks, moves })
+    }
+
+    fn part1(supplies: &Supplies<'_>) -> String {
+        determine_top_sequence(supplies, CraneModel::CrateMover9000)
+    }
+
+    fn part2(supplies: &Supplies<'_>) -> String {
+        determine_top_sequence(supplies, CraneModel::CrateMover9001)
+    }
+}
+
+pub fn determine_top_sequence(supplies: &Supplies, crane: CraneModel) -> String {
+    let mut stacks = supplies.stacks.clone();
+    handle_movements(&mut stacks, &supplies.moves, crane);
+
+    // Read top of stats
+    read_top_of_stacks(&stacks)
+}
+
+fn get_puzzle_input<'a, 'b>(lines: &'b [&'a str]) -> Result<PuzzleInput<'a, 'b>, ParseError> {
+    let split = match lines.iter().position(|line| line.is_empty()) {
+        Some(0) => {
+            return Err(ParseError::new(1, 1, "", "a drawing of the stacks"));
+        }
+        Some(split) => split,
+        // Just the stacks, with no moves to make
+        None if lines.last().is_some_and(|line| is_label_row(line)) => {
+            return Ok(PuzzleInput {
+                stack_definition: lines,
+                moves_definition: &[],
+            });
+        }
+        None => {
+            let last_line = lines.last().copied().unwrap_or("");
+            return Err(ParseError::new(
+                lines.len().max(1),
+                1,
+                last_line,
+                "a blank line between the stacks and the moves",
+            ));
+        }
+    };
+
+    Ok(PuzzleInput {
+        stack_definition : &lines[..split],
+        moves_definition : &lines[split+1..],
+    })
+}
+
+fn is_label_row(line: &str) -> bool {
+    line.chars().any(|c| c.is_ascii_digit())
+        && line.chars().all(|c| c.is_ascii_digit() || c == ' ')
+}
+
+fn parse_stacks<'a>(lines: &[&'a str]) -> Result<Vec<Vec<&'a str>>, ParseError> {
+    // Set up the vec of vecs
+    let mut stacks = provision_stacks(lines)?;
+    // Load up the stacks
+    load_stacks(&lines[..lines.len() - 1], &mut stacks)?;
+
+    Ok(stacks)
+}
+
+fn provision_stacks<'a>(rows: &[&'a str]) -> Result<Vec::<Vec::<&'a str>>, ParseError> {
+    let mut stacks = Vec::<Vec::< &str>>::new();
+    let label_row = rows.last().copied().unwrap_or("");
+    let label_regex = Regex::new(r"\d+").unwrap();
+    for _ in label_regex.find_iter(label_row) {
+        stacks.push(Vec::with_capacity(rows.len()));
+    }
+
+    if stacks.is_empty() {
+        return Err(ParseError::new(rows.len(), 1, label_row, "a row of stack numbers"));
+    }
+
+    Ok(stacks)
+}
+
+/// Each stack's crates sit in a cell of four columns, like `[A] `, so stack `i` starts at column
+/// `4 * i`. A cell holds one crate or nothing, and anything else in it is an error
+fn load_stacks<'a>(rows: &[&'a str], stacks: &mut Vec::<Vec::<&'a str>>) -> Result<(), ParseError> {
+    let stack_count = stacks.len();
+    for (row_index, row) in rows.iter().enumerate().rev() {
+        if let Some((at, c)) = row.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::new(row_index + 1, at + 1, &c.to_string(), CRATE_OR_BLANK));
+        }
+
+        for (i, start) in (0..row.len()).step_by(4).enumerate() {
+            let cell = &row[start..(start + 4).min(row.len())];
+            let cell = cell.strip_suffix(' ').unwrap_or(cell);
+            if cell.trim().is_empty() {
+                continue;
+            }
+            if !is_crate(cell) {
+                return Err(ParseError::new(row_index + 1, start + 1, cell, CRATE_OR_BLANK));
+            }
+            match stacks.get_mut(i) {
+                Some(stack) => stack.push(&cell[1..2]),
+                None => {
+                    let expected = format!("a crate above one of the {} stacks", stack_count);
+                    return Err(ParseError::new(row_index + 1, start + 1, cell, &expected));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+const CRATE_OR_BLANK: &str = "a crate like `[A]` or blank";
+
+fn is_crate(cell: &str) -> bool {
+    let bytes = cell.as_bytes();
+    bytes.len() == 3 && bytes[0] == b'[' && bytes[1].is_ascii_uppercase() && bytes[2] == b']'
+}
+
+fn handle_movements(stacks: &mut [Vec<&str>], moves: &[MoveInstruction], crane: CraneModel) {
+    for move_instruction in moves.iter() {
+        match crane {
+            CraneModel::CrateMover9000 => process_move(stacks, move_instruction),
+            CraneModel::CrateMover9001 => process_move_in_bulk(stacks, move_instruction),
+        }
+    }
+}
+
+fn parse_move(move_string: &str, stack_count: usize) -> Result<MoveInstruction, ParseError> {
+    lazy_static! {
+        static ref MOVE_REGEX: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    }
+
+    let captures = MOVE_REGEX
+        .captures(move_string)
+        .ok_or_else(|| ParseError::in_line(1, move_string, "a move like `move 1 from 2 to 3`"))?;
+    let count = captures.get(1).unwrap();
+    let count = count
+        .as_str()
+        .parse::<usize>()
+        .map_err(|_| ParseError::in_line(count.start() + 1, count.as_str(), "a crate count"))?;
+    // Stacks are numbered from 1 in the puzzle, but we index them from 0
+    let stack_index = |i: usize| {
+        let m = captures.get(i).unwrap();
+        match m.as_str().parse::<usize>() {
+            Ok(number) if (1..=stack_count).contains(&number) => Ok(number - 1),
+            _ => {
+                let expected = format!("a stack number from 1 to {}", stack_count);
+                Err(ParseError::in_line(m.start() + 1, m.as_str(), &expected))
+            }
+        }
+    };
+    let from = stack_index(2)?;
+    let to = stack_index(3)?;
+
+    Ok(MoveInstruction { count, from, to })
+}
+
+/// Replays how tall each stack gets, so no move takes more crates than its stack holds
+fn check_moves(
+    stacks: &[Vec<&str>],
+    moves: &[MoveInstruction],
+    first_move_line: usize,
+) -> Result<(), ParseError> {
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+    for (i, m) in moves.iter().enumerate() {
+        if m.count > heights[m.from] {
+            let count = m.count.to_string();
+            let expected = format!(
+                "a count of at most {}, the crates on stack {} by then",
+                heights[m.from],
+                m.from + 1
+            );
+            let line = first_move_line + i + 1;
+            return Err(ParseError::new(line, "move ".len() + 1, &count, &expected));
+        }
+        heights[m.from] -= m.count;
+        heights[m.to] += m.count;
+    }
+
+    Ok(())
+}
+
+/// Moves crates one at a time, so they land in reverse order
+fn process_move(stacks: &mut [Vec<&str>], move_instruction: &MoveInstruction) {
+    for _ in 0..move_instruction.count {
+        if let Some(from) = stacks[move_instruction.from].pop() {
+            stacks[move_instruction.to].push(from);
+        }
+    }
+}
+
+/// Moves all the crates at once, so they keep their order
+fn process_move_in_bulk(stacks: &mut [Vec<&str>], move_instruction: &MoveInstruction) {
+    let from = &mut stacks[move_instruction.from];
+    let mut to_move = from.split_off(from.len().saturating_sub(move_instruction.count));
+    let to = &mut stacks[move_instruction.to];
+    to.append(&mut to_move);
+}
+
+/// The crate on top of each stack, or a space for a stack with none left
+fn read_top_of_stacks<'a>(stacks: &'a Vec::<Vec::<&'a str>>) -> String {
+    let mut result = String::with_capacity(stacks.len());
+    for stack in stacks {
+        result.push(stack.last().and_then(|c| c.chars().next()).unwrap_or(' '));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TEST_INPUT: &str = "\x20   [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+
+    #[test]
+    fn given_test_input_returns_cmz() {
+        let supplies = Day5::parse(TEST_INPUT).unwrap();
+        let result = Day5::part1(&supplies);
+        assert_eq!(result, "CMZ");
+    }
+
+    #[test]
+    fn given_test_input_returns_mcd() {
+        let supplies = Day5::parse(TEST_INPUT).unwrap();
+        let result = Day5::part2(&supplies);
+        assert_eq!(result, "MCD");
+    }
+
+    #[test]
+    fn given_test_input_and_each_crane_model_determine_top_sequence_returns_its_answer() {
+        let supplies = Day5::parse(TEST_INPUT).unwrap();
+        assert_eq!(determine_top_sequence(&supplies, CraneModel::CrateMover9000), "CMZ");
+        assert_eq!(determine_top_sequence(&supplies, CraneModel::CrateMover9001), "MCD");
+    }
+
+    #[test]
+    fn given_test_input_get_puzzle_input_returns_correct_struct() {
+        let lines = TEST_INPUT.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines).unwrap();
+        let expected = PuzzleInput {
+            stack_definition: &lines[..4],
+            moves_definition: &lines[5..],
+        };
+
+        assert_eq!(puzzle_input, expected);
+    }
+
+    #[test]
+    fn given_test_input_parse_stacks_returns_three_stacks() {
+        let lines = TEST_INPUT.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines).unwrap();
+        let result = parse_stacks(puzzle_input.stack_definition).unwrap();
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn given_test_input_parse_stacks_input_returns_stack_with_zn() {
+        let lines = TEST_INPUT.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines).unwrap();
+        let result = parse_stacks(puzzle_input.stack_definition).unwrap();
+        assert_eq!(result[0], vec!["Z", "N"]);
+    }
+
+    #[test]
+    fn given_test_input_parse_stacks_input_returns_stack_with_mcd() {
+        let lines = TEST_INPUT.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines).unwrap();
+        let result = parse_stacks(puzzle_input.stack_definition).unwrap();
+        assert_eq!(result[1], vec!["M", "C", "D"]);
+    }
+
+    #[test]
+    fn given_test_input_parse_stacks_input_returns_stack_with_p() {
+        let lines = TEST_INPUT.lines().collect::<Vec<&str>>();
+        let puzzle_input = get_puzzle_input(&lines).unwrap();
+        let result = parse_stacks(puzzle_input.stack_definition).unwrap();
+        assert_eq!(result[2], vec!["P"]);
+    }
+
+    #[test]
+    fn given_single_move_process_move_returns_stacks_with_zn_mc_pd() {
+        let mut stacks = vec![
+            vec!["Z", "N"],
+            vec!["M", "C", "D"],
+            vec!["P"],
+        ];
+
+        let expected = vec![
+            vec!["Z", "N"],
+            vec!["M", "C"],
+            vec!["P", "D"],
+        ];
+ 
+        process_move(&mut stacks, &MoveInstruction {
+            count: 1,
+            from: 1,
+            to: 2,
+        });
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn given_multi_move_process_move_returns_stacks_with_zn_m_pdc() {
+        let mut stacks = vec![
+            vec!["Z", "N"],
+            vec!["M", "C", "D"],
+            vec!["P"],
+        ];
+
+        let expected = vec![
+            vec!["Z", "N"],
+            vec!["M"],
+            vec!["P", "D", "C"],
+        ];
+ 
+        process_move(&mut stacks, &MoveInstruction {
+            count: 2,
+            from: 1,
+            to: 2,
+        });
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn given_single_move_process_move_in_bulk_returns_stacks_with_zn_mc_pd() {
+        let mut stacks = vec![
+            vec!["Z", "N"],
+            vec!["M", "C", "D"],
+            vec!["P"],
+        ];
+
+        let expected = vec![
+            vec!["Z", "N"],
+            vec!["M", "C"],
+            vec!["P", "D"],
+        ];
+
+        process_move_in_bulk(&mut stacks, &MoveInstruction {
+            count: 1,
+            from: 1,
+            to: 2,
+        });
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn given_multi_move_process_move_in_bulk_returns_stacks_with_zn_m_pcd() {
+        let mut stacks = vec![
+            vec!["Z", "N"],
+            vec!["M", "C", "D"],
+            vec!["P"],
+        ];
+
+        let expected = vec![
+            vec!["Z", "N"],
+            vec!["M"],
+            vec!["P", "C", "D"],
+        ];
+
+        process_move_in_bulk(&mut stacks, &MoveInstruction {
+            count: 2,
+            from: 1,
+            to: 2,
+        });
+
+        assert_eq!(stacks, expected);
+    }
+
+    #[test]
+    fn given_multi_digits_parse_move_returns_move_instruction() {
+        let move_string = "move 20 from 11 to 14";
+        let expected = MoveInstruction {
+            count: 20,
+            from: 10,
+            to: 13,
+        };
+
+        let result = parse_move(move_string, 20).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_stack_zero_parse_move_returns_error_at_its_column() {
+        let result = parse_move("move 1 from 0 to 2", 3);
+        assert_eq!(
+            result,
+            Err(ParseError::in_line(13, "0", "a stack number from 1 to 3"))
+        );
+    }
+
+    #[test]
+    fn given_garbled_move_parse_returns_error_on_that_line() {
+        let input = TEST_INPUT.replace("move 3 from 1 to 3", "move three from 1 to 3");
+        let result = Day5::parse(&input);
+        assert_eq!(
+            result.map(|_| ()),
+            Err(ParseError::new(
+                7,
+                1,
+                "move three from 1 to 3",
+                "a move like `move 1 from 2 to 3`"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_no_blank_line_parse_returns_error() {
+        let result = Day5::parse(" 1   2 \nmove 1 from 1 to 2");
+        assert_eq!(
+            result.map(|_| ()),
+            Err(ParseError::new(
+                2,
+                1,
+                "move 1 from 1 to 2",
+                "a blank line between the stacks and the moves"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_move_of_more_crates_than_the_stack_holds_parse_returns_error_on_that_line() {
+        let result = Day5::parse("[A]    \n 1   2 \n\nmove 2 from 1 to 2");
+        assert_eq!(
+            result.map(|_| ()),
+            Err(ParseError::new(
+                4,
+                6,
+                "2",
+                "a count of at most 1, the crates on stack 1 by then"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_move_that_empties_a_stack_read_top_of_stacks_leaves_a_space() {
+        let supplies = Day5::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(Day5::part1(&supplies), " A");
+        assert_eq!(Day5::part2(&supplies), " A");
+    }
+
+    #[test]
+    fn given_just_the_stacks_parse_returns_no_moves() {
+        let input = aoc_core::normalise("[A] [B]\n 1   2 \n\n");
+        let supplies = Day5::parse(&input).unwrap();
+        assert!(supplies.moves.is_empty());
+        assert_eq!(Day5::part1(&supplies), "AB");
+    }
+
+    #[test]
+    fn given_crate_beyond_last_stack_parse_stacks_returns_error() {
+        let lines = ["[A] [B] [C]", " 1   2 "];
+        let result = parse_stacks(&lines);
+        assert_eq!(
+            result,
+            Err(ParseError::new(1, 9, "[C]", "a crate above one of the 2 stacks"))
+        );
+    }
+
+    #[test]
+    fn given_malformed_cell_parse_returns_error_at_that_cell() {
+        let result = Day5::parse("[D] junk [E]\n 1   2 \n\nmove 1 from 1 to 2");
+        assert_eq!(result, Err(ParseError::new(1, 5, "junk", "a crate like `[A]` or blank")));
+
+        let result = Day5::parse("[D] [x]\n 1   2 \n\nmove 1 from 1 to 2");
+        assert_eq!(result, Err(ParseError::new(1, 5, "[x]", "a crate like `[A]` or blank")));
+    }
+
+}
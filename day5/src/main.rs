@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use aoc_core::run_day;
+use day5::Day5;
+
+fn main() -> ExitCode {
+    run_day::<Day5>(5, env!("CARGO_MANIFEST_DIR"))
+}
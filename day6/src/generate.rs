@@ -0,0 +1,37 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// A datastream `size` characters long (at least 14). Only 13 letters are used until the last
+/// 14 characters, which are all different, so the start-of-message marker is always near the end
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = (0..size.saturating_sub(14))
+        .map(|_| char::from(b'a' + rng.random_range(0..13)))
+        .collect::<String>();
+
+    let mut letters = (b'a'..=b'z').map(char::from).collect::<Vec<char>>();
+    letters.shuffle(&mut rng);
+    result.extend(&letters[..14]);
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day6;
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_size_1000_generate_returns_1000_characters() {
+        let buffer = generate(1000, 1);
+        assert_eq!(buffer.trim().len(), 1000);
+    }
+
+    #[test]
+    fn given_generated_buffer_start_of_message_is_in_the_last_14() {
+        let input = generate(1000, 2);
+        let buffer = Day6::parse(&input).unwrap();
+        assert!(Day6::part2(&buffer) > 1000 - 14);
+        assert!(Day6::part1(&buffer) <= 1000);
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use aoc_core::{ParseError, Solution};
+
+mod generate;
+pub use generate::generate;
+
+pub struct Day6;
+
+#[derive(Clone, Copy)]
+enum MarkerType {
+    StartOfPacket = 4,
+    StartOfMessage = 14,
+}
+
+impl Solution for Day6 {
+    type Input<'a> = &'a str;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        Ok(input.trim())
+    }
+
+    fn part1(buffer: &&str) -> usize {
+        find_marker(buffer, &MarkerType::StartOfPacket)
+    }
+
+    fn part2(buffer: &&str) -> usize {
+        find_marker(buffer, &MarkerType::StartOfMessage)
+    }
+}
+
+/// Where the first run of `marker` all-different characters ends, or 0 if there isn't one
+fn find_marker(buffer: &str, marker: &MarkerType) -> usize {
+    let marker_length = *marker as usize;
+    let char_vec = buffer.chars().collect::<Vec<char>>();
+
+    for (start, comparison_window) in char_vec.windows(marker_length).enumerate() {
+        let comparison_set: HashSet<&char> = HashSet::from_iter(comparison_window.iter());
+
+        if comparison_set.len() == marker_length {
+            return start + marker_length
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Checks every window the slow way, comparing each pair of characters in it
+    fn naive_find_marker(buffer: &str, marker_length: usize) -> usize {
+        let chars = buffer.chars().collect::<Vec<char>>();
+        for end in marker_length..=chars.len() {
+            let window = &chars[end - marker_length..end];
+            let all_different = (0..window.len())
+                .all(|i| (i + 1..window.len()).all(|j| window[i] != window[j]));
+            if all_different {
+                return end;
+            }
+        }
+        0
+    }
+
+    #[test]
+    fn given_test_input_returns_five() {
+        let test_input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let result = find_marker(test_input, &MarkerType::StartOfPacket);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn given_test_input_start_of_message_returns_nineteen() {
+        let buffer = Day6::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        let result = Day6::part2(&buffer);
+        assert_eq!(result, 19);
+    }
+
+    #[test]
+    fn given_buffer_shorter_than_marker_find_marker_returns_zero() {
+        assert_eq!(find_marker("abc", &MarkerType::StartOfPacket), 0);
+        assert_eq!(find_marker("", &MarkerType::StartOfMessage), 0);
+    }
+
+    #[test]
+    fn given_buffer_shorter_than_marker_parts_return_zero_rather_than_panicking() {
+        let buffer = Day6::parse("abc\n").unwrap();
+        assert_eq!(Day6::part1(&buffer), 0);
+        assert_eq!(Day6::part2(&buffer), 0);
+    }
+
+    #[test]
+    fn given_no_marker_find_marker_returns_zero() {
+        assert_eq!(find_marker("abcabcabcabc", &MarkerType::StartOfPacket), 0);
+    }
+
+    proptest! {
+        // few letters, so repeats are common and markers turn up late or not at all
+        #[test]
+        fn given_any_buffer_find_marker_matches_naive_packet_search(buffer in "[a-e]{0,40}") {
+            let result = find_marker(&buffer, &MarkerType::StartOfPacket);
+            prop_assert_eq!(result, naive_find_marker(&buffer, 4));
+        }
+
+        #[test]
+        fn given_any_buffer_find_marker_matches_naive_message_search(buffer in "[a-p]{0,60}") {
+            let result = find_marker(&buffer, &MarkerType::StartOfMessage);
+            prop_assert_eq!(result, naive_find_marker(&buffer, 14));
+        }
+    }
+}
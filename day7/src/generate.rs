@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A terminal session exploring a random tree of `size` directories with up to four files in
+/// each, sized so the disk is between 45 and 65 million full (part 2 needs more than 40 used)
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let directories = size.max(1);
+
+    // Every directory but the root goes inside one made before it
+    let mut children = vec![Vec::new(); directories];
+    for d in 1..directories {
+        children[rng.random_range(0..d)].push(d);
+    }
+
+    let mut file_sizes = (0..directories)
+        .map(|_| {
+            let count = rng.random_range(0..=4);
+            (0..count)
+                .map(|_| rng.random_range(1..=1000))
+                .collect::<Vec<u64>>()
+        })
+        .collect::<Vec<Vec<u64>>>();
+    if file_sizes.iter().all(|files| files.is_empty()) {
+        file_sizes[0].push(1);
+    }
+
+    let raw_total = file_sizes.iter().flatten().sum::<u64>();
+    let disk_used = rng.random_range(45_000_000..=65_000_000);
+    for size in file_sizes.iter_mut().flatten() {
+        *size = (*size * disk_used / raw_total).max(1);
+    }
+
+    let mut result = String::from("$ cd /\n");
+    explore(&mut rng, &mut result, 0, &children, &file_sizes);
+    result
+}
+
+fn explore(
+    rng: &mut StdRng,
+    result: &mut String,
+    directory: usize,
+    children: &[Vec<usize>],
+    file_sizes: &[Vec<u64>],
+) {
+    // Directory names never have an extension, and file names always do, so they can't clash
+    let mut names = HashSet::new();
+    let mut unique_name = |rng: &mut StdRng| loop {
+        let name = (0..rng.random_range(1..=8))
+            .map(|_| char::from(b'a' + rng.random_range(0..26)))
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let directory_names = children[directory]
+        .iter()
+        .map(|_| unique_name(rng))
+        .collect::<Vec<String>>();
+
+    result.push_str("$ ls\n");
+    for name in directory_names.iter() {
+        result.push_str(&format!("dir {}\n", name));
+    }
+    for size in file_sizes[directory].iter() {
+        let extension = ["txt", "dat", "log", "lst"][rng.random_range(0..4)];
+        result.push_str(&format!("{} {}.{}\n", size, unique_name(rng), extension));
+    }
+
+    for (child, name) in children[directory].iter().zip(directory_names.iter()) {
+        result.push_str(&format!("$ cd {}\n", name));
+        explore(rng, result, *child, children, file_sizes);
+        result.push_str("$ cd ..\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day7, DISK_SIZE, DISK_SIZE_NEEDED};
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_size_200_generate_returns_200_directories() {
+        let input = generate(200, 1);
+        let listed = input.lines().filter(|l| l.starts_with("dir ")).count();
+        assert_eq!(listed + 1, 200);
+    }
+
+    #[test]
+    fn given_generated_session_disk_use_leaves_room_to_solve_part_2() {
+        let input = generate(200, 2);
+        let root = Day7::parse(&input).unwrap();
+        let used = root.borrow().total_size();
+        assert!(used > DISK_SIZE - DISK_SIZE_NEEDED && used < DISK_SIZE);
+        assert!(Day7::part2(&root) <= used);
+    }
+}
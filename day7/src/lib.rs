@@ -1,17 +1,25 @@
-use std::{cell::RefCell, path::PathBuf, rc::Rc, fs::read_to_string};
+use std::{cell::RefCell, rc::Rc};
 
+use aoc_core::{ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::{Regex, RegexBuilder};
 
+mod generate;
+pub use generate::generate;
+
+pub struct Day7;
+
 #[derive(Debug, PartialEq, Eq)]
 struct Command<'a> {
+    /// The line of the input the command was typed on, one indexed
+    line: usize,
     name: &'a str,
     args: &'a str,
     results: &'a str,
 }
 
 impl Command<'_> {
-    fn from_str(input: &str) -> Command {
+    fn from_str(line: usize, input: &str) -> Result<Command<'_>, ParseError> {
         lazy_static! {
             static ref COMMAND_REGEX: Regex = RegexBuilder::new(r"\$ (\w+)( ([^\n]*))?(\n?(.*))")
                 .dot_matches_new_line(true)
@@ -19,12 +27,19 @@ impl Command<'_> {
                 .unwrap();
         }
 
-        let caps = COMMAND_REGEX.captures(input).unwrap();
+        let first_line = input.lines().next().unwrap_or("");
+        let caps = COMMAND_REGEX
+            .captures(input)
+            .ok_or_else(|| ParseError::new(line, 1, first_line, "a command like `$ cd a` or `$ ls`"))?;
         let name = match caps.get(1) {
             Some(m) => m.as_str().trim(),
             None => "",
         };
 
+        if name != "cd" && name != "ls" {
+            return Err(ParseError::new(line, 3, name, "`cd` or `ls`"));
+        }
+
         let args = match caps.get(3) {
             Some(m) => m.as_str().trim(),
             None => "",
@@ -35,22 +50,23 @@ impl Command<'_> {
             None => "",
         };
 
-        Command {
+        Ok(Command {
+            line,
             name,
             args,
             results,
-        }
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum FileType {
+pub enum FileType {
     File,
     Directory,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct File<'a> {
+pub struct File<'a> {
     file_type: FileType,
     name: &'a str,
     size: usize,
@@ -58,7 +74,7 @@ struct File<'a> {
 }
 
 impl<'a> File<'a> {
-    fn new(file_type: FileType, name: &str, size: usize) -> File {
+    fn new(file_type: FileType, name: &str, size: usize) -> File<'_> {
         File {
             file_type,
             name,
@@ -72,7 +88,7 @@ impl<'a> File<'a> {
         self.files.append(files);
     }
 
-    fn get<'b, 'c>(&'b self, name: &'c str) -> Option<&FileNode<'a>> {
+    fn get<'b, 'c>(&'b self, name: &'c str) -> Option<&'b FileNode<'a>> {
         self.files.iter().find(|f| { 
             f.borrow().name == name 
         })
@@ -92,31 +108,65 @@ impl<'a> File<'a> {
     }
 }
 
-type FileNode<'a> = Rc<RefCell<File<'a>>>;
-
-fn main() {
-    let input = read_to_string("input.txt").unwrap();
-    let sum = find_freeable_space(&input);
-    println!("{}", sum);
-}
+pub type FileNode<'a> = Rc<RefCell<File<'a>>>;
 
 static DISK_SIZE: usize = 70000000;
 static DISK_SIZE_NEEDED: usize = 30000000;
+static SMALL_DIRECTORY_LIMIT: usize = 100000;
+
+impl Solution for Day7 {
+    type Input<'a> = FileNode<'a>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<FileNode<'_>, ParseError> {
+        // split into command strings
+        let command_strings = split_into_command_strings(input);
+        // parse commands
+        let commands = parse_commands(&command_strings)?;
+        // process commands
+        process_commands(&commands)
+    }
 
-fn find_freeable_space(input: &str) -> usize {
-    // split into command strings
-    let command_strings = split_into_command_strings(input);
-    // parse commands
-    let commands = parse_commands(&command_strings);
-    // process commands
-    let root = process_commands(&commands);
-    let used_disk = root.borrow().total_size();
-    let remaining_disk = DISK_SIZE - used_disk;
-    let needed_to_free = DISK_SIZE_NEEDED - remaining_disk;
+    fn part1(root: &FileNode<'_>) -> usize {
+        // find all dirs with size < 100000
+        sum_sizes_to_limit(root, SMALL_DIRECTORY_LIMIT)
+    }
 
+    fn part2(root: &FileNode<'_>) -> usize {
+        find_freeable_space(root)
+    }
+}
 
-    // find all dirs with size < 100000
-    find_directory_to_free(&root, needed_to_free)
+fn sum_sizes_to_limit(root: &FileNode, limit: usize) -> usize {
+    let mut queue = vec![root.clone()];
+    let mut sum: usize = 0;
+    loop {
+        if queue.is_empty() {
+            break;
+        }
+        let current = queue.remove(0);
+        let file = current.borrow();
+        let size = file.total_size();
+        if file.file_type == FileType::Directory && size <= limit {
+            sum += size;
+        }
+        queue.append(&mut current.borrow().files.to_vec());
+    }
+
+    sum
+}
+
+/// The size of the smallest directory to delete to make room for the update, or 0 if there
+/// is already room
+fn find_freeable_space(root: &FileNode) -> usize {
+    let used_disk = root.borrow().total_size();
+    // Worked out without the free space itself, which would be negative on an overfull disk
+    let needed_to_free = (used_disk + DISK_SIZE_NEEDED).saturating_sub(DISK_SIZE);
+    if needed_to_free == 0 {
+        return 0;
+    }
+
+    find_directory_to_free(root, needed_to_free)
 }
 
 fn find_directory_to_free(root: &FileNode, needed_to_free: usize) -> usize {
@@ -126,7 +176,8 @@ fn find_directory_to_free(root: &FileNode, needed_to_free: usize) -> usize {
         if dirs.len() <= index {
             break;
         }
-        let current = dirs.remove(0);
+        // Walk the list as it grows, rather than removing from it, so every directory is kept
+        let current = dirs[index].clone();
         let file = current.borrow();
         let sub_dirs = file.files.iter().filter(|f| f.borrow().file_type == FileType::Directory);
         dirs.append(&mut sub_dirs.cloned().collect::<Vec<FileNode>>());
@@ -135,34 +186,35 @@ fn find_directory_to_free(root: &FileNode, needed_to_free: usize) -> usize {
 
     // All these calls to total_size are super inefficient
     let mut sufficient_dirs = dirs.iter().filter(|f| f.borrow().total_size() >= needed_to_free).cloned().collect::<Vec<FileNode>>();
-    sufficient_dirs.sort_by(|a, b| a.borrow().total_size().cmp(&b.borrow().total_size()));
-    let space_to_free = sufficient_dirs.first().unwrap().borrow().total_size();
-    space_to_free
+    sufficient_dirs.sort_by_key(|a| a.borrow().total_size());
+    // The root is always big enough, as it holds everything that needs freeing
+    sufficient_dirs.first().map_or(0, |dir| dir.borrow().total_size())
 }
 
-fn split_into_command_strings(input: &str) -> Vec<&str> {
-    let mut result = Vec::<&str>::new();
+/// Splits the input at each `$`, pairing each command with the line it starts on
+fn split_into_command_strings(input: &str) -> Vec<(usize, &str)> {
+    let mut result = Vec::<(usize, &str)>::new();
     let command_regex = Regex::new(r"\$[^\$]*").unwrap();
     let matches = command_regex.find_iter(input);
+    let mut line = 1;
+    let mut counted_to = 0;
     for m in matches {
-        result.push(m.as_str().trim());
+        line += input[counted_to..m.start()].matches('\n').count();
+        counted_to = m.start();
+        result.push((line, m.as_str().trim()));
     }
     result
 }
 
-fn parse_commands<'a>(command_strings: &'a Vec<&str>) -> Vec<Command<'a>> {
+fn parse_commands<'a>(command_strings: &[(usize, &'a str)]) -> Result<Vec<Command<'a>>, ParseError> {
     let mut commands = Vec::<Command>::with_capacity(command_strings.len());
-    for cstr in command_strings.iter() {
-        commands.push(Command::from_str(cstr));
+    for (line, cstr) in command_strings.iter() {
+        commands.push(Command::from_str(*line, cstr)?);
     }
-    commands
+    Ok(commands)
 }
 
-fn process_commands<'a, 'b>(commands: &'a Vec<Command<'a>>) -> Rc<RefCell<File<'b>>>
-where
-    'a: 'b,
-{
-    let mut path = PathBuf::from("/");
+fn process_commands<'a>(commands: &[Command<'a>]) -> Result<FileNode<'a>, ParseError> {
     let root = Rc::new(RefCell::new(File::new(FileType::Directory, "", 0)));
     let mut stack: Vec<FileNode> = vec![root.clone()];
 
@@ -171,30 +223,45 @@ where
             if cmd.args == "/" {
                 stack.drain(1..);
             } else if cmd.args == ".." {
+                // The root is always at the bottom of the stack, so we can't leave it
+                if stack.len() == 1 {
+                    return Err(ParseError::new(cmd.line, 6, cmd.args, "a directory below the root"));
+                }
                 stack.pop();
             } else {
                 let current = stack.last().unwrap();
-                let child = current.borrow().get(cmd.args).unwrap().clone();
+                let child = current
+                    .borrow()
+                    .get(cmd.args)
+                    .filter(|child| child.borrow().file_type == FileType::Directory)
+                    .cloned()
+                    .ok_or_else(|| {
+                        let expected = "a directory listed by an earlier `ls`";
+                        ParseError::new(cmd.line, 6, cmd.args, expected)
+                    })?;
                 stack.push(child);
             }
-            path.push(&cmd.args)
         } else if cmd.name == "ls" {
+            let mut files = process_ls_results(cmd.results).map_err(|e| e.offset_lines(cmd.line))?;
             let current = stack.last().unwrap();
-            current.borrow_mut().set_files(&mut process_ls_results(&cmd.results));
+            current.borrow_mut().set_files(&mut files);
         }
     }
-    root
+    Ok(root)
 }
 
-fn process_ls_results(cmd_output: &str) -> Vec<FileNode> {
+fn process_ls_results(cmd_output: &str) -> Result<Vec<FileNode<'_>>, ParseError> {
     lazy_static! {
-        static ref LS_REGEX: Regex = Regex::new(r"((dir)|(\d+)) (\S+)").unwrap();
+        static ref LS_REGEX: Regex = Regex::new(r"^((dir)|(\d+)) (\S+)$").unwrap();
     }
 
     let files = cmd_output
         .lines()
-        .map(|l| {
-            let caps = LS_REGEX.captures(l).unwrap();
+        .enumerate()
+        .map(|(i, l)| {
+            let caps = LS_REGEX.captures(l.trim_end()).ok_or_else(|| {
+                ParseError::new(i + 1, 1, l, "a listing like `dir a` or `14848514 b.txt`")
+            })?;
             let file_type = match caps.get(2) {
                 Some(_) => FileType::Directory,
                 None => FileType::File,
@@ -206,15 +273,18 @@ fn process_ls_results(cmd_output: &str) -> Vec<FileNode> {
             };
 
             let size: usize = match caps.get(3) {
-                Some(n) => n.as_str().parse::<usize>().unwrap(),
+                Some(n) => n
+                    .as_str()
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::new(i + 1, 1, n.as_str(), "a file size"))?,
                 None => 0,
             };
 
-            Rc::new(RefCell::new(File::new(file_type, name, size)))
+            Ok(Rc::new(RefCell::new(File::new(file_type, name, size))))
         })
-        .collect::<Vec<FileNode>>();
+        .collect::<Result<Vec<FileNode>, ParseError>>()?;
 
-    files
+    Ok(files)
 }
 
 #[cfg(test)]
@@ -245,30 +315,54 @@ $ ls
 5626152 d.ext
 7214296 k";
 
+    #[test]
+    fn given_test_input_returns_95437() {
+        let root = Day7::parse(TEST_INPUT.trim()).unwrap();
+        let result = Day7::part1(&root);
+        assert_eq!(result, 95437);
+    }
+
     #[test]
     fn given_test_input_returns_24933642() {
-        let result = find_freeable_space(&TEST_INPUT.trim());
+        let root = Day7::parse(TEST_INPUT.trim()).unwrap();
+        let result = Day7::part2(&root);
         assert_eq!(result, 24933642);
     }
 
+    #[test]
+    fn given_enough_free_space_already_part2_returns_zero() {
+        let root = Day7::parse("$ cd /\n$ ls\n100 a").unwrap();
+        assert_eq!(Day7::part1(&root), 100);
+        assert_eq!(Day7::part2(&root), 0);
+        assert_eq!(Day7::part2(&Day7::parse("").unwrap()), 0);
+    }
+
+    #[test]
+    fn given_disk_fuller_than_it_is_big_part2_frees_enough_for_the_update() {
+        let input = "$ cd /\n$ ls\n80000000 a\ndir b\n$ cd b\n$ ls\n50000000 c";
+        let root = Day7::parse(input).unwrap();
+        assert_eq!(Day7::part2(&root), 130000000);
+    }
+
     #[test]
     fn given_test_input_splits_into_commands() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         assert_eq!(result.len(), 10);
+        assert_eq!(result.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![1, 2, 7, 8, 13, 14, 16, 17, 18, 19]);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd0() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd /
 "
         .trim();
-        assert_eq!(result.get(0).unwrap(), &cmd_string);
+        assert_eq!(result.first().unwrap().1, cmd_string);
     }
     #[test]
     fn given_test_input_splits_into_commands_cmd1() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ ls
 dir a
@@ -277,22 +371,22 @@ dir a
 dir d
 "
         .trim();
-        assert_eq!(result.get(1).unwrap(), &cmd_string);
+        assert_eq!(result.get(1).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd2() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd a
 "
         .trim();
-        assert_eq!(result.get(2).unwrap(), &cmd_string);
+        assert_eq!(result.get(2).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd3() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ ls
 dir e
@@ -301,63 +395,63 @@ dir e
 62596 h.lst
 "
         .trim();
-        assert_eq!(result.get(3).unwrap(), &cmd_string);
+        assert_eq!(result.get(3).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd4() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd e
 "
         .trim();
-        assert_eq!(result.get(4).unwrap(), &cmd_string);
+        assert_eq!(result.get(4).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd5() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ ls
 584 i
 "
         .trim();
-        assert_eq!(result.get(5).unwrap(), &cmd_string);
+        assert_eq!(result.get(5).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd6() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd ..
 "
         .trim();
-        assert_eq!(result.get(6).unwrap(), &cmd_string);
+        assert_eq!(result.get(6).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd7() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd ..
 "
         .trim();
-        assert_eq!(result.get(7).unwrap(), &cmd_string);
+        assert_eq!(result.get(7).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd8() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ cd d
 "
         .trim();
-        assert_eq!(result.get(8).unwrap(), &cmd_string);
+        assert_eq!(result.get(8).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_test_input_splits_into_commands_cmd9() {
-        let result = split_into_command_strings(&TEST_INPUT.trim());
+        let result = split_into_command_strings(TEST_INPUT.trim());
         let cmd_string = r"
 $ ls
 4060174 j
@@ -366,12 +460,13 @@ $ ls
 7214296 k
 "
         .trim();
-        assert_eq!(result.get(9).unwrap(), &cmd_string);
+        assert_eq!(result.get(9).unwrap().1, cmd_string);
     }
 
     #[test]
     fn given_cd_returns_correct_command() {
         let expected = Command {
+            line: 1,
             name: "cd",
             args: "/",
             results: "",
@@ -379,12 +474,13 @@ $ ls
 
         let input = "$ cd /";
 
-        assert_eq!(Command::from_str(input), expected);
+        assert_eq!(Command::from_str(1, input), Ok(expected));
     }
 
     #[test]
     fn given_ls_returns_correct_command() {
         let expected = Command {
+            line: 1,
             name: "ls",
             args: "",
             results: r"4060174 j
@@ -399,18 +495,20 @@ $ ls
 5626152 d.ext
 7214296 k";
 
-        assert_eq!(Command::from_str(input), expected);
+        assert_eq!(Command::from_str(1, input), Ok(expected));
     }
 
     #[test]
     fn given_list_of_commands_returns_correct_vector() {
         let expected = vec![
             Command {
+                line: 1,
                 name: "cd",
                 args: "/",
                 results: "",
             },
             Command {
+                line: 2,
                 name: "ls",
                 args: "",
                 results: r"4060174 j
@@ -421,15 +519,15 @@ $ ls
         ];
 
         let input = vec![
-            "$ cd /",
-            r"$ ls
+            (1, "$ cd /"),
+            (2, r"$ ls
 4060174 j
 8033020 d.log
 5626152 d.ext
-7214296 k",
+7214296 k"),
         ];
         let result = parse_commands(&input);
-        assert_eq!(result, expected);
+        assert_eq!(result, Ok(expected));
     }
 
     #[test]
@@ -466,7 +564,7 @@ $ ls
             })),
         ];
 
-        assert_eq!(process_ls_results(input), expected);
+        assert_eq!(process_ls_results(input), Ok(expected));
     }
 
     #[test]
@@ -489,18 +587,20 @@ dir foo";
             })),
         ];
 
-        assert_eq!(process_ls_results(input), expected);
+        assert_eq!(process_ls_results(input), Ok(expected));
     }
 
     #[test]
     fn given_simple_vec_of_commands_returns_files() {
         let input = vec![
             Command {
+                line: 1,
                 name: "cd",
                 args: "/",
                 results: "",
             },
             Command {
+                line: 2,
                 name: "ls",
                 args: "",
                 results: r"4060174 j
@@ -542,29 +642,33 @@ dir foo";
                 ],
         }));
 
-        assert_eq!(process_commands(&input), expected);
+        assert_eq!(process_commands(&input), Ok(expected));
     }
 
     #[test]
     fn given_nested_vec_of_commands_returns_files() {
         let input = vec![
             Command {
+                line: 1,
                 name: "cd",
                 args: "/",
                 results: "",
             },
             Command {
+                line: 2,
                 name: "ls",
                 args: "",
                 results: r"4060174 j
 dir foo",
             },
             Command {
+                line: 5,
                 name: "cd",
                 args: "foo",
                 results: "",
             },
             Command {
+                line: 6,
                 name: "ls",
                 args: "",
                 results: r"1 bar 
@@ -606,9 +710,65 @@ dir foo",
         }));
 
 
-        let result = process_commands(&input);
+        let result = process_commands(&input).unwrap();
         assert_eq!(result.borrow().name, expected.borrow().name);
         assert_eq!(result.borrow().files.len(), expected.borrow().files.len());
         assert_eq!(result.borrow().files[1].borrow().files.len(), expected.borrow().files[1].borrow().files.len());
     }
+
+    #[test]
+    fn given_unknown_command_parse_returns_error_on_that_line() {
+        let input = "$ cd /\n$ ls\ndir a\n$ rm a";
+        let result = Day7::parse(input);
+        assert_eq!(result, Err(ParseError::new(4, 3, "rm", "`cd` or `ls`")));
+    }
+
+    #[test]
+    fn given_cd_into_unlisted_directory_parse_returns_error() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd b";
+        let result = Day7::parse(input);
+        assert_eq!(
+            result,
+            Err(ParseError::new(4, 6, "b", "a directory listed by an earlier `ls`"))
+        );
+    }
+
+    #[test]
+    fn given_cd_into_a_file_parse_returns_error() {
+        let input = "$ cd /\n$ ls\n100 a.txt\n$ cd a.txt\n$ ls\n200 b.txt";
+        let result = Day7::parse(input);
+        assert_eq!(
+            result,
+            Err(ParseError::new(4, 6, "a.txt", "a directory listed by an earlier `ls`"))
+        );
+    }
+
+    #[test]
+    fn given_cd_above_root_parse_returns_error() {
+        let result = Day7::parse("$ cd /\n$ cd ..");
+        assert_eq!(result, Err(ParseError::new(2, 6, "..", "a directory below the root")));
+    }
+
+    #[test]
+    fn given_bad_listing_parse_returns_error_on_that_line() {
+        let input = "$ cd /\n$ ls\ndir a\n12kb b.txt";
+        let result = Day7::parse(input);
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                4,
+                1,
+                "12kb b.txt",
+                "a listing like `dir a` or `14848514 b.txt`"
+            ))
+        );
+    }
+
+    #[test]
+    fn given_only_root_is_big_enough_find_freeable_space_returns_root_size() {
+        let input = "$ cd /\n$ ls\ndir a\n41000000 x\n$ cd a\n$ ls\ndir b\n10000000 y\n$ cd b\n$ ls\n5000000 z";
+        let root = Day7::parse(input).unwrap();
+        let result = Day7::part2(&root);
+        assert_eq!(result, 56000000);
+    }
 }
@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use aoc_core::run_day;
+use day7::Day7;
+
+fn main() -> ExitCode {
+    run_day::<Day7>(7, env!("CARGO_MANIFEST_DIR"))
+}
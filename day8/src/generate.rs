@@ -0,0 +1,28 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A square grid of tree heights, `size` trees along each side
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            result.push(char::from(b'0' + rng.random_range(0..10)));
+        }
+        result.push('\n');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day8;
+    use aoc_core::Solution;
+
+    #[test]
+    fn given_size_20_generate_returns_20_by_20_grid() {
+        let result = Day8::parse(&generate(20, 1)).unwrap();
+        assert_eq!((result.width, result.height), (20, 20));
+    }
+}
@@ -0,0 +1,320 @@
+use aoc_core::{ParseError, Solution};
+use aoc_grid::Matrix;
+
+mod generate;
+pub use generate::generate;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Matrix<u8>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Matrix<u8>, ParseError> {
+        // Parse the text into a HeightMap (which is a Vec<u8> plus height and width)
+        Matrix::<u8>::from_string(input)
+    }
+
+    fn part1(hm: &Matrix<u8>) -> usize {
+        count_visible_trees(hm)
+    }
+
+    fn part2(hm: &Matrix<u8>) -> usize {
+        find_best_scenic_score(hm)
+    }
+}
+
+fn count_visible_trees(hm: &Matrix<u8>) -> usize {
+    // read through each row forwards and backwards, and then each col forwards and backwards,
+    let mut vm = vec![false; hm.vec_size()];
+    compute_visibility_left(hm, &mut vm);
+    compute_visibility_right(hm, &mut vm);
+    compute_visibility_down(hm, &mut vm);
+    compute_visibility_up(hm, &mut vm);
+
+    // computing 4 visibility maps (one for each direction). At each point if the current tree is
+    // taller than the tallest tree so far in that row/col then it is visible, and it is now the
+    // tallest tree so far. You could actually just do one visibility map... visibility is the
+    // existing val OR the current val
+    // HeightMap should have some index->point and point->index conversions
+    vm.iter().filter(|x| **x).count()
+}
+
+fn compute_visibility_left(hm: &Matrix<u8>, vm: &mut Vec<bool>) {
+    for r in 0..hm.height {
+        let row = hm.row_iter(r);
+        let mut max = 0u8;
+        for (c, col) in row.enumerate() {
+            let index = hm.index_from_point(c, r);
+            let mut visibility = vm[index];
+            visibility = compute_individual_visibility(&mut max, c, *col, visibility);
+            vm.splice(index..index+1, [visibility]);
+        }
+    }
+}
+
+fn compute_individual_visibility(max: &mut u8, c: usize, height: u8, visibility: bool) -> bool {
+    let mut result_visibility = visibility;
+    if c == 0 || height > *max {
+        result_visibility |= true;
+        *max = height;
+    }
+    result_visibility
+}
+
+fn compute_visibility_right(hm: &Matrix<u8>, vm: &mut Vec<bool>) {
+    for r in 0..hm.height {
+        let row = hm.row_iter(r);
+        let mut max = 0u8;
+        for (c, col) in row.rev().enumerate() {
+            let index = hm.index_from_point(hm.width - c - 1, r);
+            let mut visibility = vm[index];
+            visibility = compute_individual_visibility(&mut max, c, *col, visibility);
+            vm.splice(index..index+1, [visibility]);
+        }
+    }
+}
+
+fn compute_visibility_down(hm: &Matrix<u8>, vm: &mut Vec<bool>) {
+    for c in 0..hm.width {
+        let col = hm.col_iter(c);
+        let mut max = 0u8;
+        for (r, row) in col.enumerate() {
+            let index = hm.index_from_point(c, r);
+            let mut visibility = vm[index];
+            visibility = compute_individual_visibility(&mut max, r, *row, visibility);
+            vm.splice(index..index+1, [visibility]);
+
+        }
+    }
+}
+
+fn compute_visibility_up(hm: &Matrix<u8>, vm: &mut Vec<bool>) {
+    for c in 0..hm.width {
+        let col = hm.col_iter(c);
+        let mut max = 0u8;
+        for (r, row) in col.rev().enumerate() {
+            let index = hm.index_from_point(c, hm.height - r - 1);
+            let mut visibility = vm[index];
+            visibility = compute_individual_visibility(&mut max, r, *row, visibility);
+            vm.splice(index..index+1, [visibility]);
+        }
+    }
+}
+
+fn find_best_scenic_score(hm: &Matrix<u8>) -> usize {
+    let mut vm = Vec::<usize>::with_capacity(hm.vec_size());
+
+    // work our way through the matrix and work out the scenic score for each point
+    for row in 0..hm.height {
+        for col in 0..hm.width {
+            let score = scenic_score_for_point(hm, row, col);
+            vm.push(score);
+        }
+    }
+
+    // Find the biggest scenic score
+    let mut max_score = 0usize;
+    for score in vm.iter() {
+        if score > &max_score {
+            max_score = *score;
+        }
+    }
+
+    max_score
+}
+
+fn scenic_score_for_point(hm: &Matrix<u8>, row_index: usize, column_index: usize) -> usize {
+    let height = *hm.get_at_point(row_index, column_index);
+
+    let mut result = 1;
+
+    result *= count_trees_in_direction(height, hm.left_iter(row_index, column_index));
+    result *= count_trees_in_direction(height, hm.right_iter(row_index, column_index));
+    result *= count_trees_in_direction(height, hm.down_iter(row_index, column_index));
+    result *= count_trees_in_direction(height, hm.up_iter(row_index, column_index));
+
+    result
+}
+
+fn count_trees_in_direction<'a, I>(height: u8, dir_iter: I) -> usize
+where
+ I: Iterator<Item = &'a u8>
+{
+    let mut count = 0usize;
+    for h in dir_iter {
+        count += 1;
+        if *h >= height {
+            break
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    static INPUT: &str = r"30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn given_test_input_count_visible_trees_returns_21() {
+        let hm = Day8::parse(INPUT).unwrap();
+        let result = Day8::part1(&hm);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn given_test_input_best_score_is_8() {
+        let hm = Day8::parse(INPUT).unwrap();
+        let result = Day8::part2(&hm);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn given_test_input_scenic_score_for_2_2_returns_2() {
+        let hm = Matrix::from_string(INPUT).unwrap();
+        let result = scenic_score_for_point(&hm, 2, 2);
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn given_row_compute_visibility_left_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12321").unwrap();
+        let mut vm = vec![false; hm.vec_size()];
+        compute_visibility_left(&hm, &mut vm);
+        assert_eq!(vm, vec![true, true, true, false, false]);
+    }
+
+    #[test]
+    fn given_rows_compute_visibility_left_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12321\n32111").unwrap();
+        let mut vm = vec![false; hm.vec_size()];
+        compute_visibility_left(&hm, &mut vm);
+        assert_eq!(vm, vec![true, true, true, false, false, true, false, false, false, false]);
+    }
+
+    #[test]
+    fn given_rows_compute_visibility_right_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12321\n32111").unwrap();
+        let mut vm = vec![false; hm.vec_size()];
+        compute_visibility_right(&hm, &mut vm);
+        assert_eq!(vm, vec![false, false, true, true, true, true, true, false, false, true]);
+    }
+
+    #[test]
+    fn given_cols_compute_visibility_down_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12\n32\n51").unwrap();
+        let mut vm = vec![false; hm.vec_size()];
+        compute_visibility_down(&hm, &mut vm);
+        assert_eq!(vm, vec![true, true, true, false, true, false]);
+    }
+
+    #[test]
+    fn given_cols_compute_visibility_up_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12\n32\n51").unwrap();
+        let mut vm = vec![false; hm.vec_size()];
+        compute_visibility_up(&hm, &mut vm);
+        assert_eq!(vm, vec![false, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn given_cols_and_exisiting_map_compute_visibility_up_returns_correct_map() {
+        let hm = Matrix::<u8>::from_string("12\n32\n51").unwrap();
+        let mut vm = vec![true, false, true, false, true, false];
+        compute_visibility_up(&hm, &mut vm);
+        assert_eq!(vm, vec![true, false, true, true, true, true]);
+    }
+
+    static DIRECTIONS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+    /// The heights of the trees from (row, col) outward to the edge, nearest first
+    fn naive_line_of_sight(
+        rows: &[Vec<u8>],
+        row: usize,
+        col: usize,
+        direction: (isize, isize),
+    ) -> Vec<u8> {
+        let mut result = Vec::new();
+        let (mut r, mut c) = (row as isize + direction.0, col as isize + direction.1);
+        while r >= 0 && c >= 0 && (r as usize) < rows.len() && (c as usize) < rows[0].len() {
+            result.push(rows[r as usize][c as usize]);
+            r += direction.0;
+            c += direction.1;
+        }
+        result
+    }
+
+    fn naive_is_visible(rows: &[Vec<u8>], row: usize, col: usize) -> bool {
+        DIRECTIONS.iter().any(|&direction| {
+            naive_line_of_sight(rows, row, col, direction)
+                .iter()
+                .all(|h| *h < rows[row][col])
+        })
+    }
+
+    fn naive_scenic_score(rows: &[Vec<u8>], row: usize, col: usize) -> usize {
+        DIRECTIONS
+            .iter()
+            .map(|&direction| {
+                let line = naive_line_of_sight(rows, row, col, direction);
+                match line.iter().position(|h| *h >= rows[row][col]) {
+                    Some(blocked) => blocked + 1,
+                    None => line.len(),
+                }
+            })
+            .product()
+    }
+
+    /// Grids up to 8 trees across and down, including single rows and single columns
+    fn grid() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        (1..=8usize, 1..=8usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+        })
+    }
+
+    fn to_matrix(rows: &[Vec<u8>]) -> Matrix<u8> {
+        let text = rows
+            .iter()
+            .map(|row| row.iter().map(|h| h.to_string()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        Matrix::from_string(&text).unwrap()
+    }
+
+    #[test]
+    fn given_empty_grid_returns_no_trees_and_no_score() {
+        let hm = Day8::parse("").unwrap();
+        assert_eq!(Day8::part1(&hm), 0);
+        assert_eq!(Day8::part2(&hm), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn given_any_grid_scenic_score_for_point_matches_naive_scan(rows in grid()) {
+            let hm = to_matrix(&rows);
+            for row in 0..rows.len() {
+                for col in 0..rows[0].len() {
+                    let result = scenic_score_for_point(&hm, row, col);
+                    prop_assert_eq!(result, naive_scenic_score(&rows, row, col));
+                }
+            }
+        }
+
+        #[test]
+        fn given_any_grid_count_visible_trees_matches_naive_scan(rows in grid()) {
+            let expected = (0..rows.len())
+                .flat_map(|row| (0..rows[0].len()).map(move |col| (row, col)))
+                .filter(|&(row, col)| naive_is_visible(&rows, row, col))
+                .count();
+            prop_assert_eq!(count_visible_trees(&to_matrix(&rows)), expected);
+        }
+    }
+}
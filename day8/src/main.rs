@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use aoc_core::run_day;
+use day8::Day8;
+
+fn main() -> ExitCode {
+    run_day::<Day8>(8, env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,26 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` motions of the head, each between 1 and 20 steps
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut result = String::with_capacity(size * 5);
+    for _ in 0..size {
+        let direction = ['L', 'R', 'U', 'D'][rng.random_range(0..4)];
+        let amount = rng.random_range(1..=20);
+        result.push_str(&format!("{} {}\n", direction, amount));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn given_size_100_generate_returns_100_movements() {
+        let result = parse_input(&generate(100, 1)).unwrap();
+        assert_eq!(result.len(), 100);
+    }
+}
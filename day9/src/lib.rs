@@ -0,0 +1,179 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+mod list;
+mod rope;
+
+pub use generate::generate;
+
+const SHORT_ROPE_LENGTH: usize = 2;
+const LONG_ROPE_LENGTH: usize = 10;
+
+pub struct Day9;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Movement {
+    amount: i32,
+    direction: (i32, i32),
+}
+
+impl Movement {
+    fn new(amount: i32, direction: (i32, i32)) -> Movement {
+        Movement { amount, direction }
+    }
+}
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Movement>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Movement>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(movements: &Vec<Movement>) -> i32 {
+        rope::count_tail_positions(movements, SHORT_ROPE_LENGTH)
+    }
+
+    fn part2(movements: &Vec<Movement>) -> i32 {
+        rope::count_tail_positions(movements, LONG_ROPE_LENGTH)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Movement>, ParseError> {
+    parse_lines(input, parse_movement)
+}
+
+fn parse_movement(line: &str) -> Result<Movement, ParseError> {
+    lazy_static! {
+        static ref MR: Regex = Regex::new(r"^([LRUD]) (\d+)$").unwrap();
+    }
+
+    let caps = MR
+        .captures(line)
+        .ok_or_else(|| ParseError::in_line(1, line, "a movement like `R 4`"))?;
+    let direction = caps.get(1).unwrap().as_str();
+    let amount = caps.get(2).unwrap();
+    let amount = amount
+        .as_str()
+        .parse::<i32>()
+        .map_err(|_| ParseError::in_line(3, amount.as_str(), "a number of steps"))?;
+
+    Ok(match direction {
+        "L" => Movement::new(amount, (-1, 0)),
+        "R" => Movement::new(amount, (1, 0)),
+        "U" => Movement::new(amount, (0, -1)),
+        _ => Movement::new(amount, (0, 1)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_r4_parse_movement_returns_4_0() {
+        let result = parse_movement("R 4").unwrap();
+        assert_eq!(result, Movement::new(4, (1, 0)));
+    }
+
+    #[test]
+    fn given_l3_parse_movement_returns_m3_0() {
+        let result = parse_movement("L 3").unwrap();
+        assert_eq!(result, Movement::new(3, (-1, 0)));
+    }
+
+    #[test]
+    fn given_u2_parse_movement_returns_0_m2() {
+        let result = parse_movement("U 2").unwrap();
+        assert_eq!(result, Movement::new(2, (0, -1)));
+    }
+
+    #[test]
+    fn given_d5_parse_movement_returns_0_5() {
+        let result = parse_movement("D 5").unwrap();
+        assert_eq!(result, Movement::new(5, (0, 1)));
+    }
+
+    #[test]
+    fn given_unknown_direction_parse_input_returns_error_on_that_line() {
+        let result = parse_input("R 4\nU 4\nX 3");
+        assert_eq!(
+            result,
+            Err(ParseError::new(3, 1, "X 3", "a movement like `R 4`"))
+        );
+    }
+
+    static TEST_INPUT: &str = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    static LARGER_TEST_INPUT: &str = r"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn given_test_input_parse_input_returns_correct_sequence() {
+        let result = parse_input(TEST_INPUT).unwrap();
+        let expected = vec![
+            Movement::new(4, (1, 0)),
+            Movement::new(4, (0, -1)),
+            Movement::new(3, (-1, 0)),
+            Movement::new(1, (0, 1)),
+            Movement::new(4, (1, 0)),
+            Movement::new(1, (0, 1)),
+            Movement::new(5, (-1, 0)),
+            Movement::new(2, (1, 0)),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_larger_test_input_parse_input_returns_correct_sequence() {
+        let result = parse_input(LARGER_TEST_INPUT).unwrap();
+        let expected = vec![
+            Movement::new(5, (1, 0)),
+            Movement::new(8, (0, -1)),
+            Movement::new(8, (-1, 0)),
+            Movement::new(3, (0, 1)),
+            Movement::new(17, (1, 0)),
+            Movement::new(10, (0, 1)),
+            Movement::new(25, (-1, 0)),
+            Movement::new(20, (0, -1)),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn given_test_input_count_tail_positions_returns_13() {
+        let movements = Day9::parse(TEST_INPUT).unwrap();
+        let result = Day9::part1(&movements);
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn given_test_input_long_rope_count_tail_positions_returns_1() {
+        let movements = Day9::parse(TEST_INPUT).unwrap();
+        let result = Day9::part2(&movements);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn given_larger_test_input_count_tail_positions_returns_36() {
+        let movements = Day9::parse(LARGER_TEST_INPUT).unwrap();
+        let result = Day9::part2(&movements);
+        assert_eq!(result, 36);
+    }
+}
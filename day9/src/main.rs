@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use aoc_core::run_day;
+use day9::Day9;
+
+fn main() -> ExitCode {
+    run_day::<Day9>(9, env!("CARGO_MANIFEST_DIR"))
+}
@@ -1,39 +1,16 @@
-use lazy_static::lazy_static;
-use list::List;
-use regex::Regex;
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
 
-mod list;
-
-fn main() {
-    let input = read_to_string("input.txt").unwrap();
-    let num_positions = count_tail_positions(&input);
-    println!("{}", num_positions);
-
-}
-
-static NUM_KNOTS: usize = 10;
+use crate::list::List;
+use crate::Movement;
 
+/// A rope of `rope_length` knots, where the first knot is the head
 struct Simulation {
-    head: List<(i32, i32)>,
+    knots: List<(i32, i32)>,
     tail_positions: HashSet<(i32, i32)>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Movement {
-    amount: i32,
-    direction: (i32, i32),
-}
-
-impl Movement {
-    fn new(amount: i32, direction: (i32, i32)) -> Movement {
-        Movement { amount, direction }
-    }
-}
-
-fn count_tail_positions(input: &str) -> i32 {
-    let movements = parse_input(input);
-    let mut sim = Simulation::new();
+pub fn count_tail_positions(movements: &[Movement], rope_length: usize) -> i32 {
+    let mut sim = Simulation::new(rope_length);
     for m in movements.iter() {
         sim.apply_movement(m);
     }
@@ -41,37 +18,15 @@ fn count_tail_positions(input: &str) -> i32 {
     sim.tail_positions.len().try_into().unwrap()
 }
 
-fn parse_input(input: &str) -> Vec<Movement> {
-    input.lines().map(|l| parse_movement(l)).collect()
-}
-
-fn parse_movement(line: &str) -> Movement {
-    lazy_static! {
-        static ref MR: Regex = Regex::new(r"([LRUD]) (\d+)").unwrap();
-    }
-
-    let caps = MR.captures(line).unwrap();
-    let direction = caps.get(1).unwrap().as_str();
-    let amount = caps.get(2).unwrap().as_str().parse::<i32>().unwrap();
-
-    match direction {
-        "L" => Movement::new(amount, (-1, 0)),
-        "R" => Movement::new(amount, (1, 0)),
-        "U" => Movement::new(amount, (0, -1)),
-        "D" => Movement::new(amount, (0, 1)),
-        _ => Movement::new(0, (0, 0)),
-    }
-}
-
 impl Simulation {
-    fn new() -> Simulation {
+    fn new(rope_length: usize) -> Simulation {
         let tail_positions = HashSet::from([(0, 0)]);
-        let mut head = List::<(i32, i32)>::new();
-        for _ in 0..NUM_KNOTS {
-            head.push((0, 0));
+        let mut knots = List::<(i32, i32)>::new();
+        for _ in 0..rope_length {
+            knots.push((0, 0));
         }
         Simulation {
-            head,
+            knots,
             tail_positions,
         }
     }
@@ -114,26 +69,23 @@ impl Simulation {
             diffy = 0
         }
 
-        (diffx.min(1).max(-1), diffy.min(1).max(-1))
+        (diffx.clamp(-1, 1), diffy.clamp(-1, 1))
     }
 
     fn apply_movement(&mut self, movement: &Movement) {
         for _ in 0..movement.amount {
+            // Each knot follows the one in front of it, so the last one we see is the tail
             let mut last = (0, 0);
-            for (i, knot) in self.head.iter_mut().enumerate() {
+            for (i, knot) in self.knots.iter_mut().enumerate() {
                 if i == 0 {
                     *knot = (knot.0 + movement.direction.0, knot.1 + movement.direction.1);
-                } else {
-                    if !Self::is_adjacent(last, *knot) {
-                        let tail_movement = Self::calculate_tail_movement(last, *knot);
-                        *knot = (knot.0 + tail_movement.0, knot.1 + tail_movement.1);
-                        if i == NUM_KNOTS - 1 {
-                            self.tail_positions.insert(knot.clone());
-                        }
-                    }
+                } else if !Self::is_adjacent(last, *knot) {
+                    let tail_movement = Self::calculate_tail_movement(last, *knot);
+                    *knot = (knot.0 + tail_movement.0, knot.1 + tail_movement.1);
                 }
                 last = *knot;
             }
+            self.tail_positions.insert(last);
         }
     }
 }
@@ -142,78 +94,94 @@ impl Simulation {
 mod tests {
     use std::collections::hash_map::RandomState;
 
-    use super::*;
+    use proptest::prelude::*;
 
-    #[test]
-    fn given_0_0_0_0_r4_apply_movement_generates_correct_set() {
-        let mut sim = Simulation::new();
+    use super::*;
 
-        let mov = Movement::new(4, (1, 0));
-        sim.apply_movement(&mov);
+    /// Moves the head one step at a time, and drags each knot straight towards the one in front
+    /// whenever they are no longer touching
+    fn naive_tail_positions(movements: &[Movement], rope_length: usize) -> usize {
+        let mut knots = vec![(0i32, 0i32); rope_length];
+        let mut visited = HashSet::from([(0, 0)]);
+        for m in movements {
+            for _ in 0..m.amount {
+                knots[0] = (knots[0].0 + m.direction.0, knots[0].1 + m.direction.1);
+                for k in 1..knots.len() {
+                    let (dx, dy) = (knots[k - 1].0 - knots[k].0, knots[k - 1].1 - knots[k].1);
+                    if dx.abs() > 1 || dy.abs() > 1 {
+                        knots[k] = (knots[k].0 + dx.signum(), knots[k].1 + dy.signum());
+                    }
+                }
+                visited.insert(knots[rope_length - 1]);
+            }
+        }
+        visited.len()
+    }
 
-        let expected: HashSet<(i32, i32), RandomState> =
-            HashSet::from_iter(vec![(0, 0)].iter().cloned());
-        assert_eq!(sim.tail_positions, expected);
+    /// Short movements in any direction, including ones of zero steps
+    fn movements() -> impl Strategy<Value = Vec<Movement>> {
+        let direction = prop::sample::select(vec![(-1, 0), (1, 0), (0, -1), (0, 1)]);
+        prop::collection::vec((0..6i32, direction), 0..30).prop_map(|ms| {
+            ms.into_iter()
+                .map(|(amount, direction)| Movement::new(amount, direction))
+                .collect()
+        })
     }
 
     #[test]
-    fn given_r4_parse_movement_returns_4_0() {
-        let result = parse_movement("R 4");
-        assert_eq!(result, Movement::new(4, (1, 0)));
+    fn given_only_zero_length_movements_tail_stays_at_start() {
+        let movements = [Movement::new(0, (1, 0)), Movement::new(0, (0, -1))];
+        assert_eq!(count_tail_positions(&movements, 2), 1);
+        assert_eq!(count_tail_positions(&movements, 10), 1);
     }
 
-    #[test]
-    fn given_l3_parse_movement_returns_m3_0() {
-        let result = parse_movement("L 3");
-        assert_eq!(result, Movement::new(3, (-1, 0)));
+    proptest! {
+        #[test]
+        fn given_any_movements_count_tail_positions_matches_naive_rope(
+            movements in movements(),
+            rope_length in 1..=10usize,
+        ) {
+            let result = count_tail_positions(&movements, rope_length);
+            prop_assert_eq!(result as usize, naive_tail_positions(&movements, rope_length));
+        }
     }
 
     #[test]
-    fn given_u2_parse_movement_returns_0_m2() {
-        let result = parse_movement("U 2");
-        assert_eq!(result, Movement::new(2, (0, -1)));
+    fn given_2_knots_0_0_0_0_r4_apply_movement_generates_correct_set() {
+        let mut sim = Simulation::new(2);
+
+        let mov = Movement::new(4, (1, 0));
+        sim.apply_movement(&mov);
+
+        let expected: HashSet<(i32, i32), RandomState> =
+            HashSet::from_iter([(0, 0), (1, 0), (2, 0), (3, 0)].iter().cloned());
+        assert_eq!(sim.tail_positions, expected);
     }
 
     #[test]
-    fn given_d5_parse_movement_returns_0_5() {
-        let result = parse_movement("D 5");
-        assert_eq!(result, Movement::new(5, (0, 1)));
-    }
+    fn given_10_knots_0_0_0_0_r4_apply_movement_generates_correct_set() {
+        let mut sim = Simulation::new(10);
 
-    static TEST_INPUT: &str = r"R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
+        let mov = Movement::new(4, (1, 0));
+        sim.apply_movement(&mov);
 
-    #[test]
-    fn given_test_input_parse_input_returns_correct_sequence() {
-        let result = parse_input(&TEST_INPUT);
-        let expected = vec![
-            Movement::new(5, (1, 0)),
-            Movement::new(8, (0, -1)),
-            Movement::new(8, (-1, 0)),
-            Movement::new(3, (0, 1)),
-            Movement::new(17, (1, 0)),
-            Movement::new(10, (0, 1)),
-            Movement::new(25, (-1, 0)),
-            Movement::new(20, (0, -1)),
-        ];
-        assert_eq!(result, expected);
+        let expected: HashSet<(i32, i32), RandomState> =
+            HashSet::from_iter([(0, 0)].iter().cloned());
+        assert_eq!(sim.tail_positions, expected);
     }
 
     #[test]
-    fn given_test_input_count_tail_positions_returns_36() {
-        let result = count_tail_positions(&TEST_INPUT);
-        assert_eq!(result, 36);
+    fn given_1_knot_r4_apply_movement_tail_follows_head_exactly() {
+        let mut sim = Simulation::new(1);
+
+        let mov = Movement::new(4, (1, 0));
+        sim.apply_movement(&mov);
+
+        assert_eq!(sim.tail_positions.len(), 5);
     }
 
     #[test]
     fn given_2_2_adjacent_cells_returns_correct_results() {
-
         let result = Simulation::adjacent_cells((2, 2));
         let expected = vec![
             (2, 2),
@@ -231,12 +199,12 @@ U 20";
 
     #[test]
     fn given_2_2_1_1_is_adjacent_returns_true() {
-        assert_eq!(Simulation::is_adjacent((2, 2), (1, 1)), true);
+        assert!(Simulation::is_adjacent((2, 2), (1, 1)));
     }
 
     #[test]
     fn given_2_2_0_0_is_adjacent_returns_false() {
-        assert_eq!(Simulation::is_adjacent((2, 2), (0, 0)), false);
+        assert!(!Simulation::is_adjacent((2, 2), (0, 0)));
     }
 
     #[test]
@@ -251,7 +219,7 @@ U 20";
 
     #[test]
     fn given_1_1_2_1_movement_returns_0_0() {
-        assert_eq!(Simulation::calculate_tail_movement((1, 1), (0, 1)), (0, 0));
+        assert_eq!(Simulation::calculate_tail_movement((1, 1), (2, 1)), (0, 0));
     }
 
     #[test]